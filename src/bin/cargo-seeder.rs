@@ -0,0 +1,129 @@
+//! # cargo seeder
+//!
+//! Run `proto_seeder` as a cargo subcommand on the lib or bin target of the
+//! crate, from any directory inside it.
+
+use proto_seeder::{
+    content::{guard::GuardReturn, visibility::Visibilities},
+    generate_with_progress, generate_workspace_with_progress,
+    manifest::{find_manifest, resolve_package},
+    new_spinner, GenerateOptions, GenerateStatus,
+};
+use std::{env, path::PathBuf};
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+#[structopt(bin_name = "cargo")]
+enum Cargo {
+    /// Generate code from the Routes enum of the crate entry point.
+    Seeder(Seeder,),
+}
+
+#[derive(StructOpt, Debug)]
+struct Seeder {
+    /// Package to generate from in a workspace.
+    #[structopt(short, long)]
+    package: Option<String,>,
+
+    /// Generate for every member of the workspace depending on seed_routing.
+    #[structopt(long, conflicts_with = "package")]
+    workspace: bool,
+
+    /// Path to Cargo.toml, the closest one from the current directory by
+    /// default.
+    #[structopt(long, parse(from_os_str))]
+    manifest_path: Option<PathBuf,>,
+
+    /// Generate a `routes_test` module checking the url of every route.
+    #[structopt(short, long)]
+    tests: bool,
+
+    /// Visibility by kind of item as `kind=pub|pub(crate)|private`, comma
+    /// separated. Kinds are mod, folder_mod, tea, guard and view.
+    #[structopt(long)]
+    visibility: Option<Visibilities,>,
+
+    /// The type returned by the guards, `Option<bool>` by default, `bool` or
+    /// a type of your project.
+    #[structopt(long)]
+    guard_return: Option<GuardReturn,>,
+
+    /// The expression returned by the guards of a custom `--guard-return`,
+    /// reading the `allowed` condition.
+    #[structopt(long)]
+    guard_ok: Option<String,>,
+
+    /// Fail on warnings, such as a guard or view scope missing on the Model.
+    #[structopt(long)]
+    strict: bool,
+
+    /// Generate a `nav` view with a link to every route without payload.
+    #[structopt(long)]
+    nav: bool,
+}
+
+fn main() -> anyhow::Result<(),> {
+    let Cargo::Seeder(args,) = Cargo::from_args();
+
+    let manifest_path = match args.manifest_path {
+        Some(manifest_path,) => manifest_path,
+        None => {
+            let current_dir = env::current_dir()?;
+            find_manifest(&current_dir,).ok_or_else(|| {
+                anyhow::anyhow!("No Cargo.toml found in {} or its parents", current_dir.display())
+            },)?
+        },
+    };
+    let guard_return = args
+        .guard_return
+        .unwrap_or_default()
+        .with_expression(args.guard_ok,)
+        .map_err(|e| anyhow::anyhow!(e),)?;
+    let options = GenerateOptions::new("",)
+        .with_tests(args.tests,)
+        .with_visibilities(args.visibility.unwrap_or_default(),)
+        .with_guard_return(guard_return,)
+        .with_strict(args.strict,)
+        .with_nav(args.nav,);
+    if args.workspace {
+        let report = generate_workspace_with_progress(&manifest_path, &options, new_spinner,)?;
+        for (name, package_report,) in report.reports.iter() {
+            println!(
+                "-> {} : {:?}, created {}, updated {}, ignored {}",
+                name,
+                package_report.status,
+                package_report.file_created,
+                package_report.file_updated,
+                package_report.file_ignored
+            );
+        }
+        println!(
+            "[+] {} crates : created {}, updated {}, ignored {} files",
+            report.reports.len(),
+            report.file_created,
+            report.file_updated,
+            report.file_ignored
+        );
+        let invalid = report
+            .reports
+            .iter()
+            .filter(|(_, package_report,)| package_report.status == GenerateStatus::InvalidRoutes,)
+            .count();
+        if invalid > 0 {
+            anyhow::bail!("{} crates have issues, nothing was generated for them", invalid);
+        }
+        return Ok((),);
+    }
+
+    let package = resolve_package(&manifest_path, args.package.as_deref(),)?;
+    println!("-> {} from {}", package.name, package.entry_point.display());
+
+    generate_with_progress(
+        &GenerateOptions {
+            path: package.entry_point,
+            ..options
+        },
+        new_spinner(),
+    )?
+    .check()
+}
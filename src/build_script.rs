@@ -0,0 +1,376 @@
+//! Run proto_seeder from the `build.rs` of a Seed app.
+//!
+//! ```no_run
+//! // in the `fn main` of build.rs
+//! proto_seeder::build_script::check("src/lib.rs",).unwrap();
+//! ```
+//!
+//! In check mode nothing is written, every missing module, guard or view is
+//! reported with `cargo:warning=`. In generate mode the content is written in
+//! `OUT_DIR` unless `PROTO_SEEDER_WRITE_SOURCES=1` allows to update the source
+//! tree. The modules generated in `OUT_DIR` are gathered as inline modules in
+//! [`INCLUDE_FILE`] so the crate can include them in place of its `mod` lines,
+//! with the guards, views, nav and tests added to the copy of the target file :
+//!
+//! ```ignore
+//! // in src/lib.rs, instead of `mod pages;`
+//! include!(concat!(env!("OUT_DIR"), "/proto_seeder.rs"));
+//! ```
+//!
+//! The items the target file already has, such as `fn update` getting its
+//! dispatch arms, cannot be included twice so their changes are reported with
+//! `cargo:warning=` to be copied by hand.
+
+use crate::{
+    content::SeedContent,
+    generation::{generate as generate_content, read_content, GenerateOptions, Report},
+    parser::update::get_offset,
+    writer::checker::Checker,
+};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+use syn::{export::ToTokens, spanned::Spanned, Item};
+
+/// Env var allowing the build script to write in the source tree.
+pub const WRITE_SOURCES_ENV: &str = "PROTO_SEEDER_WRITE_SOURCES";
+
+/// File written in `OUT_DIR` with the generated modules, to `include!`.
+pub const INCLUDE_FILE: &str = "proto_seeder.rs";
+
+/// Report with `cargo:warning=` what is missing for the Routes enum of the
+/// file without writing anything.
+pub fn check(path: impl AsRef<Path,>,) -> anyhow::Result<Vec<String,>,> {
+    let path = path.as_ref();
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let content = read_content(path,)?;
+    let missing = get_missing_content(&content, fs::read_to_string(path,)?.as_str(),);
+    for message in missing.iter() {
+        println!("cargo:warning={}", message);
+    }
+    Ok(missing,)
+}
+
+/// Generate the content for the Routes enum of the file.
+/// The file is copied and updated in `OUT_DIR` unless the env var
+/// `PROTO_SEEDER_WRITE_SOURCES` is set to `1` or `true`, the modules and the
+/// new items created there are written in `OUT_DIR/proto_seeder.rs`.
+pub fn generate(path: impl AsRef<Path,>,) -> anyhow::Result<Report,> {
+    let path = path.as_ref();
+    println!("cargo:rerun-if-changed={}", path.display());
+    println!("cargo:rerun-if-env-changed={}", WRITE_SOURCES_ENV);
+
+    let out_dir = if write_sources_allowed() {
+        None
+    } else {
+        Some(PathBuf::from(env::var("OUT_DIR",)?,),)
+    };
+    generate_in(path, out_dir,)
+}
+
+/// Generate in the source tree without `out_dir`, in a copy of the file in
+/// `out_dir` otherwise.
+fn generate_in(path: &Path, out_dir: Option<PathBuf,>,) -> anyhow::Result<Report,> {
+    let target = match &out_dir {
+        None => path.to_path_buf(),
+        Some(out_dir,) => {
+            let file_name = path
+                .file_name()
+                .ok_or_else(|| anyhow::anyhow!("{} is not a file", path.display()),)?;
+            let copy = out_dir.join(file_name,);
+            fs::copy(path, &copy,)?;
+            copy
+        },
+    };
+
+    let report = generate_content(&GenerateOptions::new(target.clone(),),)?;
+    for issue in report.issues.iter() {
+        println!("cargo:warning={}", issue);
+    }
+    println!(
+        "cargo:warning=proto_seeder created {} and updated {} files from {}",
+        report.file_created,
+        report.file_updated,
+        target.display()
+    );
+    if let Some(out_dir,) = out_dir {
+        let generated = fs::read_to_string(&target,)?;
+        let mut include = get_inline_modules(&generated, &out_dir,)?;
+        let (items, changed,) = get_new_items(&fs::read_to_string(path,)?, &generated,)?;
+        include.push_str(&items,);
+        for item in changed.iter() {
+            println!(
+                "cargo:warning={} of {} is updated in {}, copy it in the source",
+                item,
+                path.display(),
+                target.display()
+            );
+        }
+        fs::write(out_dir.join(INCLUDE_FILE,), include,)?;
+    }
+    Ok(report,)
+}
+
+/// The items of the generated file that the source file does not have, with
+/// the names of the ones it has but that were changed. The `mod name;` are
+/// left to `get_inline_modules`.
+fn get_new_items(src: &str, generated: &str,) -> anyhow::Result<(String, Vec<String,>,),> {
+    let existing: Vec<(String, String,),> = syn::parse_file(src,)?
+        .items
+        .iter()
+        .filter_map(|item| item_name(item,).map(|name| (name, item_tokens(item,),),),)
+        .collect();
+    let mut items = String::new();
+    let mut changed = Vec::new();
+    for item in syn::parse_file(generated,)?.items.iter() {
+        let name = match item {
+            Item::Mod(item_mod,) if item_mod.content.is_none() => continue,
+            _ => match item_name(item,) {
+                Some(name,) => name,
+                None => continue,
+            },
+        };
+        match existing.iter().find(|(found, _,)| *found == name,) {
+            Some((_, tokens,),) if *tokens != item_tokens(item,) => changed.push(name,),
+            Some(_,) => {},
+            None => {
+                let start = get_offset(generated, item.span().start(),);
+                let end = get_offset(generated, item.span().end(),);
+                items.push_str(&format!("\n{}\n", &generated[start..end]),);
+            },
+        }
+    }
+    Ok((items, changed,),)
+}
+
+/// `fn update` or `struct Model`, for the items the generation writes.
+fn item_name(item: &Item,) -> Option<String,> {
+    match item {
+        Item::Fn(found,) => Some(format!("fn {}", found.sig.ident),),
+        Item::Struct(found,) => Some(format!("struct {}", found.ident),),
+        Item::Enum(found,) => Some(format!("enum {}", found.ident),),
+        Item::Mod(found,) => Some(format!("mod {}", found.ident),),
+        _ => None,
+    }
+}
+
+fn item_tokens(item: &Item,) -> String {
+    item.to_token_stream().to_string()
+}
+
+fn write_sources_allowed() -> bool {
+    matches!(env::var(WRITE_SOURCES_ENV).as_deref(), Ok("1") | Ok("true"))
+}
+
+/// The `mod name;` declarations of `src` whose file is in `dir`, written as
+/// inline modules with the content of their file.
+fn get_inline_modules(src: &str, dir: &Path,) -> anyhow::Result<String,> {
+    let mut modules = String::new();
+    for item in syn::parse_file(src,)?.items {
+        if let Item::Mod(item_mod,) = item {
+            if item_mod.content.is_some() {
+                continue;
+            }
+            let name = item_mod.ident.to_string();
+            let file = dir.join(format!("{}.rs", name),);
+            let folder_file = dir.join(&name,).join("mod.rs",);
+            let file = match (file.exists(), folder_file.exists(),) {
+                (true, _,) => file,
+                (_, true,) => folder_file,
+                _ => continue,
+            };
+            let start = get_offset(src, item_mod.span().start(),);
+            let end = get_offset(src, item_mod.semi.span().start(),);
+            modules.push_str(&format!(
+                "{} {{\n{}}}\n",
+                &src[start..end],
+                inline_modules(&fs::read_to_string(file,)?, &dir.join(&name,),)?
+            ),);
+        }
+    }
+    Ok(modules,)
+}
+
+/// The source of a module file where the `mod name;` with a file in `dir`
+/// are replaced by inline modules.
+fn inline_modules(src: &str, dir: &Path,) -> anyhow::Result<String,> {
+    let mut inlined = String::new();
+    let mut last = 0;
+    for item in syn::parse_file(src,)?.items {
+        if let Item::Mod(item_mod,) = item {
+            if item_mod.content.is_some() {
+                continue;
+            }
+            let start = get_offset(src, item_mod.span().start(),);
+            let end = get_offset(src, item_mod.span().end(),);
+            let module = get_inline_modules(&src[start..end], dir,)?;
+            if !module.is_empty() {
+                inlined.push_str(&src[last..start],);
+                inlined.push_str(module.trim_end(),);
+                last = end;
+            }
+        }
+    }
+    inlined.push_str(&src[last..],);
+    Ok(inlined,)
+}
+
+/// List what the generation would add, with the issues on the Routes enum.
+/// `src` is the content of the target file.
+pub fn get_missing_content(content: &SeedContent, src: &str,) -> Vec<String,> {
+    let mut missing: Vec<String,> = content
+        .route_issues()
+        .iter()
+        .map(|issue| issue.to_string(),)
+        .collect();
+
+    for (name, module,) in content.modules() {
+        let path = module.meta().filepath().display();
+        match fs::read_to_string(module.meta().filepath(),) {
+            Err(_,) => missing.push(format!("module {} is missing at {}", name, path),),
+            Ok(module_src,) => {
+                let check = Checker::store_content_for_check(&module_src,);
+                let items = [
+                    ("fn init", check.init_exist(),),
+                    ("struct Model", check.model_exist(),),
+                    ("enum Routes", check.routes_exist(),),
+                    ("enum Msg", check.message_exist(),),
+                    ("fn update", check.update_exist(),),
+                    ("fn view", check.view_exist(),),
+                ];
+                for (item, exist,) in items.iter() {
+                    if !exist {
+                        missing.push(format!("module {} at {} has no {}", name, path, item),);
+                    }
+                }
+            },
+        }
+    }
+
+    let mut functions: Vec<(&str, &str,),> = Vec::new();
+    for name in content.local_views().keys() {
+        functions.push(("view", name,),);
+    }
+    for (name, guard,) in content.guards() {
+        functions.push(("guard", name,),);
+        functions.push(("redirect view", &guard.redirect.name,),);
+    }
+    if content.fetch_state().is_some() && !Checker::check_local_type_exist("State", src,) {
+        missing.push("enum State of the fetch pages is missing".to_string(),);
+    }
+
+    let mut checked: Vec<&str,> = Vec::new();
+    for (kind, name,) in functions {
+        if checked.contains(&name,) {
+            continue;
+        }
+        checked.push(name,);
+        if !Checker::check_local_function_exist(name, src,) {
+            missing.push(format!("{} fn {} is missing", kind, name),);
+        }
+    }
+    missing
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        build_script::{check, generate_in, get_missing_content, INCLUDE_FILE},
+        constants::_FILE_WITH_ROUTES_AND_MODEL,
+        generation::read_content,
+        test_dir::TestDir,
+    };
+    use std::fs;
+
+    #[test]
+    fn test_generate_in_out_dir() {
+        let src = TestDir::new("build_script_src",);
+        let out_dir = TestDir::new("build_script_out",);
+        fs::write(src.join("lib.rs",), _FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+
+        let report = generate_in(&src.join("lib.rs",), Some(out_dir.to_path_buf(),),).unwrap();
+        assert_eq!(report.modules, 4);
+        assert!(!src.join("pages",).exists());
+        assert_eq!(
+            fs::read_to_string(src.join("lib.rs",),).unwrap(),
+            _FILE_WITH_ROUTES_AND_MODEL
+        );
+
+        let modules = fs::read_to_string(out_dir.join(INCLUDE_FILE,),).unwrap();
+        let parsed_file = syn::parse_file(&modules,).unwrap();
+        assert!(modules.starts_with("mod pages {\n"));
+        assert!(modules.contains("pub mod login {\n"));
+        assert!(modules.contains("pub mod settings {\n"));
+        assert!(!modules.contains("mod login;"));
+        let functions: Vec<String,> = parsed_file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Fn(found,) => Some(found.sig.ident.to_string(),),
+                _ => None,
+            },)
+            .collect();
+        for name in ["admin_guard", "forbidden_user", "not_found",].iter() {
+            assert!(functions.iter().any(|found| found == name), "{}", name);
+        }
+        assert!(!functions.iter().any(|found| found == "init"));
+
+        generate_in(&src.join("lib.rs",), Some(out_dir.to_path_buf(),),).unwrap();
+        assert_eq!(fs::read_to_string(out_dir.join(INCLUDE_FILE,),).unwrap(), modules);
+    }
+
+    #[test]
+    fn test_check_dotted_scope() {
+        let dir = TestDir::new("build_script_scope",);
+        let src = _FILE_WITH_ROUTES_AND_MODEL
+            .replace("logged_user: Option<LoggedData>,", "session: session::Session,",)
+            .replace("\"logged_user =>", "\"session.user =>",);
+        fs::write(dir.join("lib.rs",), format!("mod session;\n{}", src),).unwrap();
+        fs::write(
+            dir.join("session.rs",),
+            "pub struct Session {\n    pub user: Option<LoggedData>,\n}\n",
+        )
+        .unwrap();
+
+        let missing = check(dir.join("lib.rs",),).unwrap();
+        assert!(!missing.iter().any(|m| m.contains("not found on Model")), "{:?}", missing);
+        assert!(missing.contains(&"guard fn admin_guard is missing".to_string()));
+    }
+
+    #[test]
+    fn test_get_missing_content() {
+        let dir = TestDir::new("build_script",);
+        fs::create_dir_all(dir.join("pages",),).unwrap();
+        let src = format!(
+            "{}\nfn home(theme : &Theme) -> Node<Msg>{{div![\"home\"]}}\n",
+            _FILE_WITH_ROUTES_AND_MODEL
+        );
+        fs::write(dir.join("lib.rs",), &src,).unwrap();
+        fs::write(dir.join("pages/login.rs",), "pub fn init() {}\npub fn view() {}\n",).unwrap();
+
+        let content = read_content(&dir.join("lib.rs",),).unwrap();
+        let missing = get_missing_content(&content, &src,);
+        let login_path = dir.join("pages/login.rs",);
+        let settings_path = dir.join("pages/settings.rs",);
+
+        assert!(missing.contains(&format!(
+            "module settings is missing at {}",
+            settings_path.display()
+        )));
+        assert!(missing.contains(&format!(
+            "module login at {} has no struct Model",
+            login_path.display()
+        )));
+        assert!(!missing.contains(&format!(
+            "module login at {} has no fn init",
+            login_path.display()
+        )));
+        assert!(missing.contains(&"view fn not_found is missing".to_string()));
+        assert!(missing.contains(&"guard fn admin_guard is missing".to_string()));
+        assert!(missing.contains(&"redirect view fn forbidden_user is missing".to_string()));
+        assert!(!missing.contains(&"view fn home is missing".to_string()));
+        assert_eq!(missing.iter().filter(|m| m.contains("fn forbidden ")).count(), 1);
+    }
+}
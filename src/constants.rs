@@ -261,3 +261,25 @@ fn guard() {
 }
 
  "###;
+
+pub(crate) const _FILE_WITH_ROUTES_COLLISIONS: &str = r###"
+
+struct Model {
+    logged_user: Option<LoggedData>,
+}
+
+#[derive(Debug, PartialEq, Clone, RoutingModules)]
+pub enum Routes {
+    Login {
+        query: IndexMap<String, String,>,
+    },
+    #[as_path = "login"]
+    SignIn,
+    #[default_route]
+    #[view = " => not_found"]
+    NotFound,
+    #[default_route]
+    #[view = " => home"]
+    Home,
+}
+ "###;
@@ -0,0 +1,69 @@
+//! Files of a new Seed app with its routes, written by `proto_seeder new`.
+
+use crate::content::module::templates::app::{
+    _APP_CARGO_TEMPLATE, _APP_DEFAULT_VARIANT_TEMPLATE, _APP_FIELD_TEMPLATE, _APP_INDEX_TEMPLATE,
+    _APP_INIT_TEMPLATE, _APP_LIB_TEMPLATE, _APP_MAKEFILE_TEMPLATE, _APP_MESSAGE_TEMPLATE,
+    _APP_VARIANT_TEMPLATE,
+};
+use convert_case::{Case, Casing};
+
+/// The files of the app by path from its directory, the `lib.rs` declares a
+/// module for each route to let the generation write the pages.
+pub fn get_app_files(name: &str, routes: &[String],) -> Vec<(&'static str, String,),> {
+    let mut variants = String::new();
+    let mut fields = String::new();
+    let mut inits = String::new();
+    let mut messages = String::new();
+    for (index, route,) in routes.iter().enumerate() {
+        let route = route.to_case(Case::Pascal,);
+        let module = route.to_case(Case::Snake,);
+        if index == 0 {
+            variants.push_str(_APP_DEFAULT_VARIANT_TEMPLATE,);
+        }
+        variants.push_str(&_APP_VARIANT_TEMPLATE.replace("ROUTE", &route,),);
+        fields.push_str(&_APP_FIELD_TEMPLATE.replace("NAME", &module,),);
+        inits.push_str(&_APP_INIT_TEMPLATE.replace("NAME", &module,),);
+        messages.push_str(
+            &_APP_MESSAGE_TEMPLATE
+                .replace("ROUTE", &route,)
+                .replace("NAME", &module,),
+        );
+    }
+    let lib = _APP_LIB_TEMPLATE
+        .replace("VARIANTS", &variants,)
+        .replace("FIELDS", &fields,)
+        .replace("INITS", &inits,)
+        .replace("MESSAGES", &messages,);
+    vec![
+        ("Cargo.toml", _APP_CARGO_TEMPLATE.replace("NAME", name,),),
+        ("index.html", _APP_INDEX_TEMPLATE.replace("NAME", name,),),
+        ("Makefile.toml", _APP_MAKEFILE_TEMPLATE.to_string(),),
+        ("src/lib.rs", lib,),
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        content::app::get_app_files,
+        parser::{find_message, find_model, find_routes},
+    };
+
+    #[test]
+    fn test_get_app_files() {
+        let routes = vec!["Home".to_string(), "user_settings".to_string()];
+        let files = get_app_files("my_app", &routes,);
+        let names: Vec<&str,> = files.iter().map(|(name, _,)| *name,).collect();
+        assert_eq!(names, vec!["Cargo.toml", "index.html", "Makefile.toml", "src/lib.rs"]);
+        assert!(files[0].1.contains("name = \"my_app\""));
+
+        let lib = syn::parse_file(&files[3].1,).unwrap();
+        let routes = find_routes(&lib,).unwrap();
+        assert_eq!(routes.variants.len(), 2);
+        assert_eq!(routes.variants[1].ident, "UserSettings");
+        assert!(routes.variants[0].attrs.iter().any(|a| a.path.is_ident("default_route")));
+        assert!(find_model(&lib,).is_some());
+        assert_eq!(find_message(&lib,).unwrap().variants.len(), 4);
+        assert!(files[3].1.contains("    user_settings: pages::user_settings::Model,\n"));
+    }
+}
@@ -0,0 +1,94 @@
+//! Reusable component with a setter per prop, written in its own file by
+//! `proto_seeder component`.
+
+use crate::content::module::templates::component::{
+    _COMPONENT_CLASS_TEMPLATE, _COMPONENT_DEFAULT_TEMPLATE, _COMPONENT_FIELD_TEMPLATE,
+    _COMPONENT_NODE_TEMPLATE, _COMPONENT_SETTER_TEMPLATE, _COMPONENT_TEMPLATE,
+};
+use convert_case::{Case, Casing};
+
+/// The component and the name of its module.
+#[derive(Debug, Clone)]
+pub struct SeedComponent {
+    /// Name of the struct, `Card`.
+    pub name: String,
+    /// Name of the module and of the file, `card`.
+    pub module: String,
+    pub content: String,
+}
+
+/// The component built like `TopBar`, generic over the message with a setter,
+/// a default and a node for each prop given as name and type.
+pub fn get_component(name: &str, props: &[(String, String,)],) -> SeedComponent {
+    let name = name.to_case(Case::Pascal,);
+    let class = name.to_case(Case::Kebab,);
+    let mut fields = String::new();
+    let mut setters = String::new();
+    let mut defaults = String::new();
+    let mut classes = String::new();
+    let mut nodes = String::new();
+    for (prop, ty,) in props.iter() {
+        fields.push_str(&_COMPONENT_FIELD_TEMPLATE.replace("PROP", prop,).replace("TYPE", ty,),);
+        setters.push_str(&_COMPONENT_SETTER_TEMPLATE.replace("PROP", prop,).replace("TYPE", ty,),);
+        defaults.push_str(&_COMPONENT_DEFAULT_TEMPLATE.replace("PROP", prop,),);
+        let prop_class = prop.to_case(Case::Kebab,);
+        if ty == "bool" {
+            classes.push_str(
+                &_COMPONENT_CLASS_TEMPLATE
+                    .replace("PROP", prop,)
+                    .replace("CLASS", &prop_class,),
+            );
+        } else {
+            nodes.push_str(
+                &_COMPONENT_NODE_TEMPLATE
+                    .replace("PROP", prop,)
+                    .replace("CLASS", &format!("{}-{}", class, prop_class),),
+            );
+        }
+    }
+    let content = _COMPONENT_TEMPLATE
+        .replace("FIELDS", &fields,)
+        .replace("SETTERS", &setters,)
+        .replace("DEFAULTS", &defaults,)
+        .replace("CLASSES", &classes,)
+        .replace("NODES", &nodes,)
+        .replace("CLASS", &class,)
+        .replace("NAME", &name,);
+    SeedComponent {
+        module: name.to_case(Case::Snake,),
+        name,
+        content,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::content::component::get_component;
+
+    #[test]
+    fn test_get_component() {
+        let props = vec![
+            ("title".to_string(), "String".to_string(),),
+            ("is_disabled".to_string(), "bool".to_string(),),
+        ];
+        let component = get_component("user_card", &props,);
+
+        assert_eq!(component.name, "UserCard");
+        assert_eq!(component.module, "user_card");
+        assert!(syn::parse_file(&component.content,).is_ok(), "{}", component.content);
+        assert!(component
+            .content
+            .contains("pub struct UserCard<Ms: 'static,> {\n    title: String,\n"));
+        assert!(component
+            .content
+            .contains("pub fn is_disabled(mut self, is_disabled: impl Into<bool,>,) -> Self {"));
+        assert!(component.content.contains("            title: Default::default(),\n"));
+        assert!(component
+            .content
+            .contains("C![\"user-card\", IF!(self.is_disabled => \"is-disabled\")],"));
+        assert!(component
+            .content
+            .contains("div![C![\"user-card-title\"], self.title.to_string()],"));
+        assert!(component.content.contains("impl<Ms,> UpdateEl<Ms,> for UserCard<Ms,> {"));
+    }
+}
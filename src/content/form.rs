@@ -0,0 +1,226 @@
+//! Form over a struct of the crate with a message per field, written in a
+//! module by `proto_seeder form`.
+
+use crate::content::{
+    module::{
+        crud::get_simple_fields,
+        templates::form::{
+            _FORM_ARM_TEMPLATE, _FORM_INPUT_TEMPLATE, _FORM_MESSAGE_TEMPLATE,
+            _FORM_MODEL_FIELD_TEMPLATE, _FORM_MODEL_TEMPLATE, _FORM_PARSED_ARM_TEMPLATE,
+            _FORM_UPDATE_TEMPLATE, _FORM_USE_TEMPLATE, _FORM_VARIANT_TEMPLATE, _FORM_VIEW_TEMPLATE,
+        },
+    },
+    ContentSettings,
+};
+use convert_case::{Case, Casing};
+use indexmap::map::IndexMap;
+use syn::{export::ToTokens, ItemStruct, Visibility};
+
+/// The content of the form, the writer only adds the parts missing on the
+/// module.
+#[derive(Debug, Clone)]
+pub struct SeedForm {
+    /// Name of the struct, `LoginCredentials`.
+    pub item: String,
+    /// `use` of the struct from the crate root.
+    pub import: String,
+    /// Field of the Model holding the struct, `login_credentials`.
+    pub field: String,
+    /// Declaration of the field when the Model misses it.
+    pub model_field: String,
+    /// Model with the field when the module has none.
+    pub model: String,
+    /// Variant of Msg -> its declaration and its arm in `update`.
+    pub variants: IndexMap<String, (String, String,),>,
+    /// Msg with every variant when the module has none.
+    pub msg: String,
+    /// `update` with every arm when the module has none.
+    pub update: String,
+    /// Name of the view, `login_credentials_form`.
+    pub view_name: String,
+    pub view: String,
+    /// The fields without input, private with no setter or getter.
+    pub ignored: Vec<String,>,
+}
+
+/// The form over the struct, bound to the field of the Model with its type or
+/// to a new one. The setters and getters of the struct are used when it has
+/// them, a private field without them gets no input.
+pub fn get_form(
+    item: &ItemStruct,
+    item_path: &str,
+    model: Option<&ItemStruct,>,
+    settings: &ContentSettings,
+) -> SeedForm {
+    let tea = settings.visibilities.tea;
+    let item_name = item.ident.to_string();
+    let field = model
+        .and_then(|model| {
+            model.fields.iter().find(|f| {
+                let ty = f.ty.to_token_stream().to_string().replace(' ', "",);
+                ty == item_name || ty.ends_with(&format!("::{}", item_name),)
+            },)
+        },)
+        .and_then(|f| f.ident.as_ref(),)
+        .map(|ident| ident.to_string(),)
+        .unwrap_or_else(|| item_name.to_case(Case::Snake,),);
+    let model_field = tea.apply(
+        &_FORM_MODEL_FIELD_TEMPLATE
+            .replace("NAME", &field,)
+            .replace("ITEM", &item_name,),
+    );
+
+    let segments: Vec<String,> = item_path.split("::",).map(str::to_string,).collect();
+    let item_module = match segments.split_last() {
+        Some((_, module,),) if module.first().map(String::as_str,) == Some("crate",) => {
+            &module[1..]
+        },
+        Some((_, module,),) => module,
+        None => &[],
+    };
+    let has_method = |method: &str| settings.index.has_method(item_module, &item_name, method,);
+
+    let mut variants = IndexMap::new();
+    let mut inputs = String::new();
+    let mut ignored = Vec::new();
+    for (name, ty,) in get_simple_fields(item,) {
+        let variant = format!("{}Changed", name.to_case(Case::Pascal,));
+        let setter = format!("set_{}", name);
+        let public = item
+            .fields
+            .iter()
+            .any(|f| f.ident.as_ref().is_some_and(|i| i == &name,) && is_public(&f.vis,),);
+        let set = if has_method(&setter,) {
+            format!("model.{}.{}(value,)", field, setter)
+        } else if public {
+            format!("model.{}.{} = value", field, name)
+        } else {
+            ignored.push(format!(
+                "No input for field {} [ => ] as it is private and {} has no fn {}",
+                name, item_name, setter
+            ),);
+            continue;
+        };
+        let arm = if ty == "String" {
+            _FORM_ARM_TEMPLATE
+        } else {
+            _FORM_PARSED_ARM_TEMPLATE
+        };
+        let value = if has_method(&name,) {
+            format!("model.{}.{}()", field, name)
+        } else if public {
+            format!("model.{}.{}", field, name)
+        } else {
+            ignored.push(format!(
+                "No input for field {} [ => ] as it is private and {} has no fn {}",
+                name, item_name, name
+            ),);
+            continue;
+        };
+        inputs.push_str(
+            &_FORM_INPUT_TEMPLATE
+                .replace("LABEL", &name.to_case(Case::Title,),)
+                .replace("VALUE", &value,)
+                .replace("VARIANT", &variant,),
+        );
+        variants.insert(
+            variant.clone(),
+            (
+                _FORM_VARIANT_TEMPLATE.replace("VARIANT", &variant,),
+                arm.replace("VARIANT", &variant,).replace("SET", &set,),
+            ),
+        );
+    }
+
+    let declarations: String = variants.values().map(|(variant, _,)| variant.as_str(),).collect();
+    let arms: String = variants.values().map(|(_, arm,)| arm.as_str(),).collect();
+    let view_name = format!("{}_form", item_name.to_case(Case::Snake,));
+    SeedForm {
+        import: _FORM_USE_TEMPLATE.replace("ITEM_PATH", item_path,),
+        model: tea.apply(&_FORM_MODEL_TEMPLATE.replace("FIELD", &model_field,),),
+        msg: tea.apply(&_FORM_MESSAGE_TEMPLATE.replace("VARIANTS", &declarations,),),
+        update: tea.apply(&_FORM_UPDATE_TEMPLATE.replace("ARMS", &arms,),),
+        view: settings.visibilities.views.apply(
+            &_FORM_VIEW_TEMPLATE
+                .replace("NAME", &view_name,)
+                .replace("ITEM", &item_name,)
+                .replace("INPUTS", &inputs,),
+        ),
+        item: item_name,
+        field,
+        model_field,
+        variants,
+        view_name,
+        ignored,
+    }
+}
+
+/// The form is written in another module than the struct, so only `pub` and
+/// `pub(crate)` fields can be read and assigned there.
+fn is_public(vis: &Visibility,) -> bool {
+    match vis {
+        Visibility::Public(_,) | Visibility::Crate(_,) => true,
+        Visibility::Restricted(restricted,) => restricted.path.is_ident("crate",),
+        Visibility::Inherited => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        content::{form::get_form, scope::CrateIndex, ContentSettings},
+        parser::{find_model, find_structs},
+    };
+
+    const FORM_FILE: &str = r###"
+pub struct Settings {
+    pub theme: String,
+    pub page_size: u32,
+    pub tags: Vec<String>,
+}
+
+impl Settings {
+    pub fn set_theme(&mut self, theme: String,) {
+        self.theme = theme;
+    }
+}
+
+pub struct Model {
+    pub current: crate::Settings,
+}
+"###;
+
+    #[test]
+    fn test_get_form() {
+        let parsed_file = syn::parse_file(FORM_FILE,).unwrap();
+        let mut index = CrateIndex::default();
+        index.add_module(Vec::new(), &parsed_file.items,);
+        let settings = ContentSettings {
+            index,
+            ..ContentSettings::default()
+        };
+        let item = &find_structs(&parsed_file,)[0];
+        let model = find_model(&parsed_file,).unwrap();
+
+        let form = get_form(item, "crate::Settings", Some(&model,), &settings,);
+        assert_eq!(form.field, "current");
+        assert_eq!(form.view_name, "settings_form");
+        assert_eq!(form.variants.keys().collect::<Vec<_,>>(), vec![
+            "ThemeChanged",
+            "PageSizeChanged"
+        ]);
+        assert_eq!(
+            form.variants["ThemeChanged"].1,
+            "        Msg::ThemeChanged(value,) => model.current.set_theme(value,),\n"
+        );
+        assert!(form.variants["PageSizeChanged"].1.contains("model.current.page_size = value;"));
+        assert!(form.view.contains("model.current.page_size.to_string()"));
+        for section in [&form.model, &form.msg, &form.update, &form.view,].iter() {
+            assert!(syn::parse_file(section,).is_ok(), "{}", section);
+        }
+
+        let form = get_form(item, "crate::Settings", None, &settings,);
+        assert_eq!(form.field, "settings");
+        assert_eq!(form.model_field, "    pub settings: Settings,\n");
+    }
+}
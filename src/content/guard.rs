@@ -2,15 +2,22 @@
 
 use crate::{
     content::{
-        get_scoped_field,
-        module::templates::guard::_GUARD_TEMPLATE,
+        module::templates::guard::{
+            _GUARD_CONDITION_BODY, _GUARD_DEFAULT_BODY, _GUARD_TEMPLATE,
+        },
+        scope::CrateIndex,
         view::{get_view_function, SeedView},
-        SeedRoute,
+        ContentSettings, SeedRoute,
+    },
+    parser::{
+        route::{get_as_path_attribute, is_default_route},
+        view::get_guard_attribute,
     },
-    parser::view::get_guard_attribute,
 };
+use convert_case::{Case, Casing};
 use indexmap::map::IndexMap;
-use syn::{export::ToTokens, ItemEnum, ItemStruct};
+use std::{fmt, str::FromStr};
+use syn::{ItemEnum, ItemStruct};
 
 #[derive(Debug, Clone)]
 pub struct SeedGuard {
@@ -20,7 +27,141 @@ pub struct SeedGuard {
     pub(crate) routes: Vec<SeedRoute,>,
 }
 
-pub fn get_guards(routes_enum: &ItemEnum, model: ItemStruct,) -> IndexMap<String, SeedGuard,> {
+/// The type returned by the generated guards.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub enum GuardReturn {
+    /// `Option<bool>`, the default.
+    #[default]
+    OptionBool,
+    /// `bool`
+    Bool,
+    /// A type from the project, returned by the expression given with
+    /// `--guard-ok` which reads the `allowed` condition.
+    Custom {
+        ty: String,
+        expression: Option<String,>,
+    },
+}
+
+impl GuardReturn {
+    /// Set the expression returned by the guards of a custom type, which is
+    /// required for them and refused for the others.
+    pub fn with_expression(self, expression: Option<String,>,) -> Result<Self, String,> {
+        match (self, expression,) {
+            (
+                GuardReturn::Custom {
+                    ty, ..
+                },
+                Some(expression,),
+            ) => Ok(GuardReturn::Custom {
+                ty,
+                expression: Some(expression,),
+            },),
+            (_, Some(_,),) => {
+                Err("--guard-ok is only used with a custom --guard-return".to_string(),)
+            },
+            (guard_return, None,) => guard_return.check().map(|_| guard_return,),
+        }
+    }
+
+    /// A custom type needs the expression building it.
+    pub fn check(&self,) -> Result<(), String,> {
+        match self {
+            GuardReturn::Custom {
+                ty,
+                expression: None,
+            } => Err(format!(
+                "--guard-return {} needs --guard-ok with the expression to return, such as \
+                 \"if allowed {{ Ok(()) }} else {{ Err(Redirect::Login) }}\"",
+                ty
+            ),),
+            _ => Ok((),),
+        }
+    }
+
+    /// The last expression of the guard from the `allowed` condition if any.
+    fn returned(&self, condition: bool,) -> String {
+        match (self, condition,) {
+            (GuardReturn::OptionBool, true,) => "Some(allowed)".to_string(),
+            (GuardReturn::OptionBool, false,) => "None".to_string(),
+            (GuardReturn::Bool, true,) => "allowed".to_string(),
+            (GuardReturn::Bool, false,) => "false".to_string(),
+            (
+                GuardReturn::Custom {
+                    expression: Some(expression,),
+                    ..
+                },
+                true,
+            ) => expression.clone(),
+            (
+                GuardReturn::Custom {
+                    expression: Some(expression,),
+                    ..
+                },
+                false,
+            ) => format!("let allowed = false;\n{}", expression),
+            // refused by `check` before generating, the type has to be built
+            // by hand
+            (
+                GuardReturn::Custom {
+                    ty,
+                    expression: None,
+                },
+                _,
+            ) => format!("// return a {} from the condition\nDefault::default()", ty),
+        }
+    }
+
+    /// The condition checking that the call to the guard allows the route,
+    /// none for a custom type.
+    pub fn allows(&self, call: &str,) -> Option<String,> {
+        match self {
+            GuardReturn::OptionBool => Some(format!("{} == Some(true,)", call),),
+            GuardReturn::Bool => Some(call.to_string(),),
+            GuardReturn::Custom {
+                ..
+            } => None,
+        }
+    }
+}
+
+impl FromStr for GuardReturn {
+    type Err = String;
+
+    fn from_str(s: &str,) -> Result<Self, Self::Err,> {
+        let mut ty = s.to_string();
+        ty.retain(|c| !c.is_whitespace(),);
+        match ty.as_str() {
+            "" => Err("the guard return type cannot be empty".to_string(),),
+            "Option<bool>" => Ok(GuardReturn::OptionBool,),
+            "bool" => Ok(GuardReturn::Bool,),
+            _ => Ok(GuardReturn::Custom {
+                ty: s.trim().to_string(),
+                expression: None,
+            },),
+        }
+    }
+}
+
+impl fmt::Display for GuardReturn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_,>,) -> fmt::Result {
+        match self {
+            GuardReturn::OptionBool => write!(f, "Option<bool>"),
+            GuardReturn::Bool => write!(f, "bool"),
+            GuardReturn::Custom {
+                ty, ..
+            } => write!(f, "{}", ty),
+        }
+    }
+}
+
+/// A guard used by several routes is generated once, from its first
+/// declaration. Different declarations are reported by `check_guards`.
+pub fn get_guards(
+    routes_enum: &ItemEnum,
+    model: ItemStruct,
+    settings: &ContentSettings,
+) -> IndexMap<String, SeedGuard,> {
     let mut map: IndexMap<String, SeedGuard,> = IndexMap::new();
 
     for v in routes_enum.variants.iter() {
@@ -28,9 +169,17 @@ pub fn get_guards(routes_enum: &ItemEnum, model: ItemStruct,) -> IndexMap<String
             get_guard_attribute(v.ident.clone(), v.attrs.iter(),)
         {
             let function_content =
-                get_guard_function(model_scope.as_str(), guard.as_str(), &model,);
-            let redirect_function =
-                get_view_function(model_scope.as_str(), redirect.as_str(), &model,);
+                get_guard_function(model_scope.as_str(), guard.as_str(), &model, settings,);
+            let redirect_function = format!(
+                "{}{}",
+                settings.visibilities.views.prefix(),
+                get_view_function(
+                    model_scope.as_str(),
+                    redirect.as_str(),
+                    &model,
+                    &settings.index,
+                )
+            );
             if let Some(g,) = map.get_mut(&guard,) {
                 g.routes.push(SeedRoute {
                     name: v.ident.clone().to_string(),
@@ -39,6 +188,8 @@ pub fn get_guards(routes_enum: &ItemEnum, model: ItemStruct,) -> IndexMap<String
                     children: false,
                     id_param: false,
                     query: false,
+                    as_path: get_as_path_attribute(v.attrs.iter(),),
+                    default_route: is_default_route(v.attrs.iter(),),
                 },)
             } else {
                 map.insert(
@@ -55,6 +206,8 @@ pub fn get_guards(routes_enum: &ItemEnum, model: ItemStruct,) -> IndexMap<String
                                 children: false,
                                 id_param: false,
                                 query: false,
+                                as_path: get_as_path_attribute(v.attrs.iter(),),
+                                default_route: is_default_route(v.attrs.iter(),),
                                 content_to_load: redirect_function.clone(),
                             },
                         },
@@ -65,6 +218,8 @@ pub fn get_guards(routes_enum: &ItemEnum, model: ItemStruct,) -> IndexMap<String
                             children: false,
                             id_param: false,
                             query: false,
+                            as_path: get_as_path_attribute(v.attrs.iter(),),
+                            default_route: is_default_route(v.attrs.iter(),),
                         }],
                     },
                 );
@@ -74,59 +229,137 @@ pub fn get_guards(routes_enum: &ItemEnum, model: ItemStruct,) -> IndexMap<String
     map
 }
 
-/// todo add Model extractor to match the scope
-pub fn get_guard_function(model_scope: &str, guard: &str, model: &ItemStruct,) -> String {
+/// The scope can be a dotted path through the structs of the crate such as
+/// `session.user`.
+pub fn get_guard_function(
+    model_scope: &str,
+    guard: &str,
+    model: &ItemStruct,
+    settings: &ContentSettings,
+) -> String {
     let scope = if model_scope.is_empty() {
         None
     } else {
-        model
-            .fields
-            .iter()
-            .find(|field| get_scoped_field(model_scope.to_string(), field,),)
+        settings.index.resolve_scope(model, model_scope,)
     };
     // fix it with Model
     let template = _GUARD_TEMPLATE;
 
-    let payload = match scope {
-        None => {
-            println!(
-                "scope {} not found on Model {} so we inject Model instead",
-                model_scope, model.ident
-            );
-            "model : &Model".to_string()
-        },
+    let (payload, condition,) = match scope {
+        None => ("model : &Model".to_string(), None,),
         Some(s,) => {
-            let scope_type = &mut s.ty.to_token_stream().to_string();
-            scope_type.retain(|c| !c.is_whitespace(),);
-
-            let ident = &s.ident.as_ref().expect("Should have get property name",);
-            format!("{} : &{}", ident.to_string(), scope_type,)
+            let condition = get_guard_condition(&s.ident, &s.ty, guard, &settings.index,);
+            (format!("{} : &{}", s.ident, s.ty,), condition,)
         },
     };
 
-    template
+    let body = match &condition {
+        Some(condition,) => _GUARD_CONDITION_BODY.replace("CONDITION", condition,),
+        None => _GUARD_DEFAULT_BODY.to_string(),
+    }
+    .replace("RETURN", settings.guard_return.returned(condition.is_some(),).as_str(),);
+
+    settings
+        .visibilities
+        .guards
+        .apply(template,)
         .replace("PAYLOAD", payload.as_str(),)
         .replace("GUARD_NAME", guard,)
+        .replace("RETURN_TYPE", settings.guard_return.to_string().as_str(),)
+        .replace("BODY", body.as_str(),)
+}
+
+/// Guess the condition of the guard from the type of the scoped field.
+/// - `bool` -> `*scope`
+/// - `Option<_>` -> `scope.is_some()`
+/// - `Vec<_>` or `String` -> `!scope.is_empty()`
+/// - a struct of the crate with a `role` field -> a role check named after
+///   the guard, `admin_guard` checks `Role::Admin`.
+fn get_guard_condition(
+    ident: &str,
+    scope_type: &str,
+    guard: &str,
+    index: &CrateIndex,
+) -> Option<String,> {
+    if scope_type == "bool" {
+        return Some(format!("*{}", ident),);
+    }
+    if scope_type == "String" || scope_type.starts_with("Vec<",) {
+        return Some(format!("!{}.is_empty()", ident),);
+    }
+    if let Some(inner,) = scope_type
+        .strip_prefix("Option<",)
+        .and_then(|t| t.strip_suffix('>',),)
+    {
+        return match get_role_check("value", inner, guard, index,) {
+            Some(check,) => Some(format!(
+                "{}.as_ref().map_or(false, |value| {})",
+                ident, check
+            ),),
+            None => Some(format!("{}.is_some()", ident),),
+        };
+    }
+    get_role_check(ident, scope_type, guard, index,)
+}
+
+/// Compare the `role` field of the struct to the role named after the guard.
+fn get_role_check(
+    ident: &str,
+    struct_type: &str,
+    guard: &str,
+    index: &CrateIndex,
+) -> Option<String,> {
+    let role = guard.strip_suffix("_guard",).filter(|r| !r.is_empty(),)?;
+    let (module, found,) = index.resolve_struct(&[], struct_type,)?;
+    let role_field = found.fields.iter().find(|f| {
+        f.ident
+            .as_ref()
+            .map(|i| i == "role",)
+            .unwrap_or(false,)
+    },)?;
+    let role_type = index.qualify(&module, &role_field.ty,);
+    if role_type == "String" {
+        Some(format!("{}.role == \"{}\"", ident, role),)
+    } else {
+        Some(format!(
+            "{}.role == {}::{}",
+            ident,
+            role_type,
+            role.to_case(Case::Pascal,)
+        ),)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::{
         constants::_FILE_WITH_ROUTES_AND_MODEL,
-        content::{module::templates::guard::_GUARD_TEMPLATE, SeedContent, SeedRoute},
+        content::{
+            guard::{get_guard_function, GuardReturn},
+            module::templates::guard::{_GUARD_DEFAULT_BODY, _GUARD_TEMPLATE},
+            ContentSettings, SeedContent, SeedRoute,
+        },
         find_model, find_routes,
     };
-    use indexmap::map::IndexMap;
 
     fn get_guard() -> String {
         _GUARD_TEMPLATE
+            .replace("VISIBILITY ", "",)
             .replace("PAYLOAD", "model : &Model",)
             .replace("GUARD_NAME", "guard",)
+            .replace("RETURN_TYPE", "Option<bool>",)
+            .replace("BODY", &_GUARD_DEFAULT_BODY.replace("RETURN", "None",),)
     }
     fn get_admin_guard() -> String {
         _GUARD_TEMPLATE
+            .replace("VISIBILITY ", "",)
             .replace("PAYLOAD", "logged_user : &Option<LoggedData>",)
             .replace("GUARD_NAME", "admin_guard",)
+            .replace("RETURN_TYPE", "Option<bool>",)
+            .replace(
+                "BODY",
+                "let allowed = logged_user.is_some();\nSome(allowed)",
+            )
     }
 
     #[test]
@@ -137,8 +370,6 @@ mod test {
 
         let content = SeedContent::new(routes_enum.unwrap(), model.unwrap(), "", "",);
 
-        let mut should_have: IndexMap<String, (String, SeedRoute,),> = IndexMap::new();
-
         let guard = content.guards.get("guard",).unwrap();
         assert_eq!(guard.routes.len(), 2);
 
@@ -150,6 +381,8 @@ mod test {
                 children: false,
                 id_param: false,
                 query: false,
+                as_path: None,
+                default_route: false,
                 content_to_load: get_guard()
             }
         );
@@ -161,6 +394,8 @@ mod test {
                 children: false,
                 id_param: false,
                 query: false,
+                as_path: None,
+                default_route: false,
                 content_to_load: get_guard()
             }
         );
@@ -173,10 +408,82 @@ mod test {
                 children: false,
                 id_param: false,
                 query: false,
+                as_path: None,
+                default_route: false,
                 content_to_load: get_admin_guard()
             }
         );
 
         assert_eq!(admin_guard.routes.len(), 1);
     }
+
+    const ROLES: &str = r###"
+struct Model {
+    logged_user: Option<LoggedData>,
+    admin: User,
+    is_logged: bool,
+    friends: Vec<String>,
+    session: Session,
+}
+struct LoggedData {
+    name: String,
+    role: Role,
+}
+struct User {
+    role: String,
+}
+"###;
+
+    #[test]
+    fn test_get_guard_function() {
+        let parsed_file = syn::parse_file(ROLES,).unwrap();
+        let model = find_model(&parsed_file,).unwrap();
+        let mut settings = ContentSettings::default();
+        settings.index.add_module(Vec::new(), &parsed_file.items,);
+        let guard = |scope: &str, name: &str, settings: &ContentSettings| {
+            get_guard_function(scope, name, &model, settings,)
+        };
+
+        assert!(guard("logged_user", "admin_guard", &settings).contains(
+            "let allowed = logged_user.as_ref().map_or(false, |value| value.role == \
+             Role::Admin);\nSome(allowed)"
+        ));
+        assert!(guard("logged_user", "guard", &settings)
+            .contains("let allowed = logged_user.is_some();"));
+        assert!(guard("admin", "super_admin_guard", &settings)
+            .contains("let allowed = admin.role == \"super_admin\";"));
+        assert!(guard("is_logged", "user_guard", &settings).contains("let allowed = *is_logged;"));
+        assert!(guard("friends", "user_guard", &settings)
+            .contains("let allowed = !friends.is_empty();"));
+        assert!(guard("session", "user_guard", &settings).contains("None\n}"));
+
+        let bool_settings = ContentSettings {
+            guard_return: "bool".parse().unwrap(),
+            ..settings.clone()
+        };
+        let result = guard("is_logged", "user_guard", &bool_settings,);
+        assert!(result.contains("fn user_guard(is_logged : &bool) -> bool\n"));
+        assert!(result.contains("let allowed = *is_logged;\nallowed\n}"));
+
+        let custom: GuardReturn = "Result<(), Redirect>".parse().unwrap();
+        assert_eq!(custom, GuardReturn::Custom {
+            ty: "Result<(), Redirect>".to_string(),
+            expression: None,
+        });
+        assert!(custom.check().is_err());
+        assert!(custom.clone().with_expression(None,).is_err());
+        assert!(GuardReturn::Bool.with_expression(Some("Ok(())".to_string(),),).is_err());
+        let expression = "if allowed { Ok(()) } else { Err(Redirect::Login) }";
+        let custom_settings = ContentSettings {
+            guard_return: custom.with_expression(Some(expression.to_string(),),).unwrap(),
+            ..settings
+        };
+        assert!(custom_settings.guard_return.check().is_ok());
+        let result = guard("session", "user_guard", &custom_settings,);
+        assert!(result.contains("-> Result<(), Redirect>\n"));
+        assert!(result.contains(&format!("let allowed = false;\n{}\n}}", expression)));
+        let result = guard("is_logged", "user_guard", &custom_settings,);
+        assert!(result.contains(&format!("let allowed = *is_logged;\n{}\n}}", expression)));
+        assert!(!result.contains("todo!"));
+    }
 }
@@ -3,18 +3,42 @@
 
 use crate::{
     content::{
-        guard::{get_guards, SeedGuard},
-        module::{get_modules, import::ImportModule, SeedModule},
+        guard::{get_guards, GuardReturn, SeedGuard},
+        module::{
+            crud::scaffold_cruds, fetch::scaffold_fetches, get_dispatch_arms, get_modules,
+            import::ImportModule, nested::dispatch_nested_routes,
+            templates::fetch::_FETCH_STATE_TEMPLATE, SeedModule,
+        },
+        scope::CrateIndex,
         view::{get_local_views, SeedView},
+        visibility::Visibilities,
     },
-    parser::module::modules_path,
+    parser::{
+        get_attribute_location,
+        module::modules_path,
+        route::{get_as_path_attribute, is_default_route},
+        view::{get_guard_attribute, get_view_attribute},
+    },
+    path::normalize,
 };
+use convert_case::{Case, Casing};
 use indexmap::map::IndexMap;
-use syn::{Field, ItemEnum, ItemStruct};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+use syn::{Attribute, Fields, ItemEnum, ItemStruct};
 
+pub mod app;
+pub mod component;
+pub mod form;
 pub mod guard;
 pub mod module;
+pub mod nav;
+pub mod routing_test;
+pub mod scope;
 pub mod view;
+pub mod visibility;
 
 /// Routing Seed concept extracted from the parser
 /// This struct contains all thew views, guard, modules ( init ,views, update,
@@ -31,6 +55,19 @@ pub struct SeedContent {
     parent_module: ImportModule,
     /// TEA files with full content.
     modules: IndexMap<String, SeedModule,>,
+    /// Every variant of the Routes enum.
+    routes: IndexMap<String, SeedRoute,>,
+    /// Routing tests for the target file, only when asked from the cli.
+    routes_tests: Option<String,>,
+    /// Navigation menu for the target file, only when asked from the cli.
+    nav: Option<String,>,
+    /// Arms of the root `update` for the messages of every module.
+    dispatch_arms: IndexMap<String, String,>,
+    /// Problems on the `#[guard]` and `#[view]` attributes.
+    attribute_issues: Vec<RouteIssue,>,
+    /// `State` of the `#[fetch]` pages for the target file, only when a
+    /// module requests its data.
+    fetch_state: Option<String,>,
 }
 
 impl SeedContent {
@@ -49,39 +86,138 @@ impl SeedContent {
     pub fn modules(&self,) -> &IndexMap<String, SeedModule,> {
         &self.modules
     }
+
+    pub fn routes(&self,) -> &IndexMap<String, SeedRoute,> {
+        &self.routes
+    }
+
+    pub fn dispatch_arms(&self,) -> &IndexMap<String, String,> {
+        &self.dispatch_arms
+    }
+
+    pub fn fetch_state(&self,) -> &Option<String,> {
+        &self.fetch_state
+    }
+
+    pub fn routes_tests(&self,) -> &Option<String,> {
+        &self.routes_tests
+    }
+
+    pub fn set_routes_tests(&mut self, routes_tests: String,) -> &mut Self {
+        self.routes_tests = Some(routes_tests,);
+        self
+    }
+
+    pub fn nav(&self,) -> &Option<String,> {
+        &self.nav
+    }
+
+    pub fn set_nav(&mut self, nav: String,) -> &mut Self {
+        self.nav = Some(nav,);
+        self
+    }
+
+    /// Dispatch the view and the init of the modules on their nested routes,
+    /// given the `Routes` and the Model of the module files by route name.
+    pub fn set_nested_routes(
+        &mut self,
+        nested_routes: &IndexMap<String, (ItemEnum, ItemStruct,),>,
+        settings: &ContentSettings,
+    ) -> &mut Self {
+        for module in self.modules.values_mut() {
+            let name = match module.origin_route() {
+                Some(route,) if module.crud().is_none() && module.fetch().is_none() => {
+                    route.name.clone()
+                },
+                _ => continue,
+            };
+            if let Some((routes, model,),) = nested_routes.get(&name,) {
+                dispatch_nested_routes(module, routes, model, settings,);
+            }
+        }
+        self
+    }
+
+    /// Report problems on default route and paths before writing anything.
+    pub fn route_issues(&self,) -> Vec<RouteIssue,> {
+        let mut issues = check_routes(&self.routes,);
+        issues.extend(self.attribute_issues.iter().cloned(),);
+        issues
+    }
+}
+
+/// How to write the content.
+#[derive(Clone, Default)]
+pub struct ContentSettings {
+    /// `pub`, `pub(crate)` or private for each kind of item.
+    pub visibilities: Visibilities,
+    /// The type returned by the guards.
+    pub guard_return: GuardReturn,
+    /// The modules of the crate, used to resolve the scopes and to guess the
+    /// body of the guards.
+    pub index: CrateIndex,
 }
 
 impl SeedContent {
+    /// The paths are normalized so `./my_app/src/` and `./my_app//src` give
+    /// the same module paths.
     pub fn new(
         routes_enum: ItemEnum,
         model: ItemStruct,
-        root_path_file: &str,
-        target_file_path: &str,
+        root_path_file: impl AsRef<Path,>,
+        target_file_path: impl AsRef<Path,>,
+    ) -> Self {
+        SeedContent::new_with_settings(
+            routes_enum,
+            model,
+            root_path_file,
+            target_file_path,
+            &ContentSettings::default(),
+        )
+    }
+
+    /// Same as `new` with the visibilities and the guard settings.
+    pub fn new_with_settings(
+        routes_enum: ItemEnum,
+        model: ItemStruct,
+        root_path_file: impl AsRef<Path,>,
+        target_file_path: impl AsRef<Path,>,
+        settings: &ContentSettings,
     ) -> Self {
         let parent_module = modules_path(&routes_enum.attrs.iter(),);
 
-        let (modules, import_module,) = get_modules(
+        let (mut modules, import_module,) = get_modules(
             routes_enum.clone(),
-            parent_module,
-            root_path_file,
-            target_file_path,
+            parent_module.clone(),
+            &normalize(root_path_file.as_ref(),),
+            &normalize(target_file_path.as_ref(),),
+            &settings.visibilities,
         );
+        let mut attribute_issues = check_guards(&routes_enum,);
+        let file = normalize(target_file_path.as_ref(),);
+        attribute_issues.extend(check_scopes(&routes_enum, &model, &settings.index, &file,),);
+        attribute_issues.extend(scaffold_cruds(&routes_enum, &mut modules, settings, &file,),);
+        attribute_issues.extend(scaffold_fetches(&routes_enum, &mut modules, settings, &file,),);
+        let fetch_state = if modules.values().any(|module| module.fetch().is_some(),) {
+            Some(settings.visibilities.tea.apply(_FETCH_STATE_TEMPLATE,),)
+        } else {
+            None
+        };
         SeedContent {
-            local_views: get_local_views(&routes_enum, model.clone(),),
-            guards: get_guards(&routes_enum, model,),
+            local_views: get_local_views(&routes_enum, model.clone(), settings,),
+            guards: get_guards(&routes_enum, model.clone(), settings,),
             parent_module: import_module,
+            dispatch_arms: get_dispatch_arms(&modules, parent_module.as_deref(),),
             modules,
+            routes: get_routes(&routes_enum,),
+            routes_tests: None,
+            nav: None,
+            attribute_issues,
+            fetch_state,
         }
     }
 }
 
-fn get_scoped_field(scope: String, field: &&Field,) -> bool {
-    if let Some(ident,) = &field.ident {
-        *ident == scope
-    } else {
-        false
-    }
-}
 /// Route that loads a specific content.
 #[derive(Debug, PartialEq, Clone)]
 pub struct SeedRoute {
@@ -90,23 +226,404 @@ pub struct SeedRoute {
     pub children: bool,
     pub id_param: bool,
     pub query: bool,
+    /// Custom path from `#[as_path = "..."]`, can be empty for the root.
+    pub as_path: Option<String,>,
+    /// Set by `#[default_route]`.
+    pub default_route: bool,
     pub content_to_load: String,
 }
 
+impl SeedRoute {
+    /// The segment seed_routing will match for this route, the `#[as_path]`
+    /// if any or the variant name in snake case.
+    pub fn path_segment(&self,) -> String {
+        match &self.as_path {
+            Some(path,) => path.trim_matches('/',).to_string(),
+            None => self.name.to_case(Case::Snake,),
+        }
+    }
+
+    /// The url handled by the route with its payload, ex: `/admin/:id/*`.
+    pub fn url_path(&self,) -> String {
+        let mut parts: Vec<String,> = Vec::new();
+        let segment = self.path_segment();
+        if !segment.is_empty() {
+            parts.push(segment,);
+        }
+        if self.id_param {
+            parts.push(":id".to_string(),);
+        }
+        if self.nested || self.children {
+            parts.push("*".to_string(),);
+        }
+        let path = format!("/{}", parts.join("/"));
+        if self.query {
+            format!("{}?query", path)
+        } else {
+            path
+        }
+    }
+}
+
+/// Where an attribute is written, shown as `file:line:column`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Location {
+    pub file: PathBuf,
+    /// Starting at 1.
+    pub line: usize,
+    /// Starting at 1.
+    pub column: usize,
+}
+
+impl Location {
+    /// The location of the first attribute with the given name in the file.
+    pub fn of_attribute(
+        file: &Path,
+        attribute_name: &str,
+        attrs: std::slice::Iter<'_, Attribute,>,
+    ) -> Option<Location,> {
+        get_attribute_location(attribute_name, attrs,).map(|(line, column,)| Location {
+            file: file.to_path_buf(),
+            line,
+            column,
+        },)
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_,>,) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file.display(), self.line, self.column)
+    }
+}
+
+/// Problem on the Routes enum that should be reported before generating.
+#[derive(Debug, PartialEq, Clone)]
+pub enum RouteIssue {
+    /// No variant has `#[default_route]`.
+    MissingDefaultRoute,
+    /// More than one variant has `#[default_route]`.
+    MultipleDefaultRoutes(Vec<String,>,),
+    /// Several variants are matched by the same path.
+    PathCollision {
+        path: String,
+        routes: Vec<String,>,
+    },
+    /// The Model scope of a `#[guard]` or a `#[view]` does not exist, the
+    /// Model is given to the function instead.
+    ScopeNotFound {
+        route: String,
+        /// `guard` or `view`.
+        attribute: String,
+        scope: String,
+        /// Where the attribute is in the target file.
+        location: Option<Location,>,
+        /// The closest existing scope.
+        suggestion: Option<String,>,
+    },
+    /// The same guard is declared with different scopes or redirects, only
+    /// one function can be generated for it.
+    GuardConflict {
+        guard: String,
+        /// The first route of each declaration with its `#[guard]` value.
+        declarations: Vec<(String, String,),>,
+    },
+    /// The struct of a `#[crud]` is not in the crate, the module is generated
+    /// without the pages.
+    CrudStructNotFound {
+        route: String,
+        path: String,
+        location: Option<Location,>,
+    },
+    /// A `#[crud]` is on a route without nested routes for its pages.
+    CrudWithoutNestedRoutes {
+        route: String,
+        location: Option<Location,>,
+    },
+    /// A `#[fetch]` is on a route without `id` to request the data with.
+    FetchWithoutId {
+        route: String,
+        location: Option<Location,>,
+    },
+    /// A `#[fetch]` is on a route with nested routes, its module dispatches
+    /// them instead of requesting the data.
+    FetchWithNestedRoutes {
+        route: String,
+        location: Option<Location,>,
+    },
+}
+
+impl RouteIssue {
+    /// Errors stop the generation, the others are only reported.
+    pub fn is_error(&self,) -> bool {
+        !matches!(
+            self,
+            RouteIssue::MissingDefaultRoute
+                | RouteIssue::ScopeNotFound { .. }
+                | RouteIssue::CrudStructNotFound { .. }
+                | RouteIssue::CrudWithoutNestedRoutes { .. }
+                | RouteIssue::FetchWithoutId { .. }
+                | RouteIssue::FetchWithNestedRoutes { .. }
+        )
+    }
+}
+
+impl fmt::Display for RouteIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_,>,) -> fmt::Result {
+        match self {
+            RouteIssue::MissingDefaultRoute => {
+                write!(f, "no #[default_route] found on Routes")
+            },
+            RouteIssue::MultipleDefaultRoutes(routes,) => write!(
+                f,
+                "only one #[default_route] is allowed but found it on {}",
+                routes.join(", ")
+            ),
+            RouteIssue::PathCollision {
+                path,
+                routes,
+            } => write!(
+                f,
+                "routes {} are all matching the path \"/{}\"",
+                routes.join(", "),
+                path
+            ),
+            RouteIssue::ScopeNotFound {
+                route,
+                attribute,
+                scope,
+                location,
+                suggestion,
+            } => {
+                if let Some(location,) = location {
+                    write!(f, "{} : ", location)?;
+                }
+                write!(
+                    f,
+                    "scope \"{}\" of #[{}] on {} not found on Model",
+                    scope, attribute, route
+                )?;
+                if let Some(suggestion,) = suggestion {
+                    write!(f, ", did you mean \"{}\" ?", suggestion)?;
+                }
+                write!(f, " Model is injected instead")
+            },
+            RouteIssue::GuardConflict {
+                guard,
+                declarations,
+            } => write!(
+                f,
+                "guard {} is declared differently on {}, use the same scope and redirect or \
+                 rename the guard",
+                guard,
+                declarations
+                    .iter()
+                    .map(|(route, declaration,)| format!("{} \"{}\"", route, declaration),)
+                    .collect::<Vec<String,>>()
+                    .join(" and ",)
+            ),
+            RouteIssue::CrudStructNotFound {
+                route,
+                path,
+                location,
+            } => {
+                if let Some(location,) = location {
+                    write!(f, "{} : ", location)?;
+                }
+                write!(
+                    f,
+                    "struct \"{}\" of #[crud] on {} not found in the crate, the module is \
+                     generated without its pages",
+                    path, route
+                )
+            },
+            RouteIssue::CrudWithoutNestedRoutes {
+                route,
+                location,
+            } => {
+                if let Some(location,) = location {
+                    write!(f, "{} : ", location)?;
+                }
+                write!(
+                    f,
+                    "#[crud] on {} needs nested routes such as {}(pages::{}::Routes), the module \
+                     is generated without its pages",
+                    route,
+                    route,
+                    route.to_case(Case::Snake)
+                )
+            },
+            RouteIssue::FetchWithoutId {
+                route,
+                location,
+            } => {
+                if let Some(location,) = location {
+                    write!(f, "{} : ", location)?;
+                }
+                write!(
+                    f,
+                    "#[fetch] on {} needs an id such as {} {{ id: String }}, the module is \
+                     generated without the request",
+                    route, route
+                )
+            },
+            RouteIssue::FetchWithNestedRoutes {
+                route,
+                location,
+            } => {
+                if let Some(location,) = location {
+                    write!(f, "{} : ", location)?;
+                }
+                write!(
+                    f,
+                    "#[fetch] on {} is ignored as its module dispatches its nested routes, put it \
+                     on a nested route of pages::{}::Routes instead",
+                    route,
+                    route.to_case(Case::Snake)
+                )
+            },
+        }
+    }
+}
+
+/// Report the guards declared with different scopes or redirects.
+pub fn check_guards(routes_enum: &ItemEnum,) -> Vec<RouteIssue,> {
+    let mut declarations: IndexMap<String, Vec<(String, String,),>,> = IndexMap::new();
+    for v in routes_enum.variants.iter() {
+        if let Some((scope, guard, redirect,),) =
+            get_guard_attribute(v.ident.clone(), v.attrs.iter(),)
+        {
+            let declaration = format!("{} => {} => {}", scope, guard, redirect);
+            let found = declarations.entry(guard,).or_default();
+            if !found.iter().any(|(_, d,)| *d == declaration,) {
+                found.push((v.ident.to_string(), declaration,),);
+            }
+        }
+    }
+    declarations
+        .into_iter()
+        .filter(|(_, found,)| found.len() > 1,)
+        .map(|(guard, declarations,)| RouteIssue::GuardConflict {
+            guard,
+            declarations,
+        },)
+        .collect()
+}
+
+/// Report the scopes of `#[guard]` and `#[view]` that are not found on the
+/// Model.
+pub fn check_scopes(
+    routes_enum: &ItemEnum,
+    model: &ItemStruct,
+    index: &CrateIndex,
+    file: &Path,
+) -> Vec<RouteIssue,> {
+    let mut issues: Vec<RouteIssue,> = Vec::new();
+    for v in routes_enum.variants.iter() {
+        let guard_scope = get_guard_attribute(v.ident.clone(), v.attrs.iter(),)
+            .map(|(scope, _, _,)| ("guard", scope,),);
+        let view_scope = get_view_attribute(v.ident.clone(), v.attrs.iter(),)
+            .map(|(scope, _,)| ("view", scope,),);
+        for (attribute, scope,) in guard_scope.into_iter().chain(view_scope,) {
+            if scope.is_empty() {
+                continue;
+            }
+            if let Err(missing,) = index.find_scope(model, &scope,) {
+                issues.push(RouteIssue::ScopeNotFound {
+                    route: v.ident.to_string(),
+                    attribute: attribute.to_string(),
+                    scope,
+                    location: Location::of_attribute(file, attribute, v.attrs.iter(),),
+                    suggestion: missing.suggestion(),
+                },);
+            }
+        }
+    }
+    issues
+}
+
+/// Extract every variant of the Routes enum with its url payload and path.
+pub fn get_routes(routes_enum: &ItemEnum,) -> IndexMap<String, SeedRoute,> {
+    let mut map: IndexMap<String, SeedRoute,> = IndexMap::new();
+    for v in routes_enum.variants.iter() {
+        let has_field = |name: &str| match &v.fields {
+            Fields::Named(fields,) => fields
+                .named
+                .iter()
+                .any(|f| f.ident.as_ref().is_some_and(|ident| ident == name,),),
+            _ => false,
+        };
+        let route = SeedRoute {
+            name: v.ident.to_string(),
+            nested: matches!(v.fields, Fields::Unnamed(_)),
+            children: has_field("children",),
+            id_param: has_field("id",),
+            query: has_field("query",),
+            as_path: get_as_path_attribute(v.attrs.iter(),),
+            default_route: is_default_route(v.attrs.iter(),),
+            content_to_load: "".to_string(),
+        };
+        map.insert(route.name.clone(), route,);
+    }
+    map
+}
+
+/// Check the default route and the path collisions between variants.
+pub fn check_routes(routes: &IndexMap<String, SeedRoute,>,) -> Vec<RouteIssue,> {
+    let mut issues: Vec<RouteIssue,> = Vec::new();
+
+    let default_routes: Vec<String,> = routes
+        .values()
+        .filter(|r| r.default_route,)
+        .map(|r| r.name.clone(),)
+        .collect();
+    match default_routes.len() {
+        0 => issues.push(RouteIssue::MissingDefaultRoute,),
+        1 => {},
+        _ => issues.push(RouteIssue::MultipleDefaultRoutes(default_routes,),),
+    }
+
+    let mut paths: IndexMap<String, Vec<String,>,> = IndexMap::new();
+    for route in routes.values() {
+        paths
+            .entry(route.path_segment(),)
+            .or_default()
+            .push(route.name.clone(),);
+    }
+    for (path, names,) in paths {
+        if names.len() > 1 {
+            issues.push(RouteIssue::PathCollision {
+                path,
+                routes: names,
+            },);
+        }
+    }
+    issues
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
-        constants::{_FILE_WITH_ROUTES_AND_MODEL, _FILE_WITH_ROUTES_AND_MODEL_WITHOUT_PAGES},
-        content::{view::SeedView, SeedContent, SeedRoute},
+        constants::{
+            _FILE_WITH_ROUTES_AND_MODEL, _FILE_WITH_ROUTES_AND_MODEL_WITHOUT_PAGES,
+            _FILE_WITH_ROUTES_COLLISIONS,
+        },
+        content::{
+            view::SeedView,
+            visibility::{Visibilities, Visibility},
+            ContentSettings, RouteIssue, SeedContent, SeedRoute,
+        },
         find_model, find_routes,
     };
+    use std::path::Path;
     use indexmap::map::IndexMap;
 
-    const FORBIDDEN_VIEW: &str =
-        r###"fn forbidden(logged_user : &Option<LoggedData>) -> Node<Msg>{div!["forbidden"]}"###;
+    const FORBIDDEN_VIEW: &str = r###"// -> /forbidden
+fn forbidden(logged_user : &Option<LoggedData>) -> Node<Msg>{div!["forbidden"]}"###;
 
-    const NOT_FOUND: &str = r###"fn not_found(model : &Model) -> Node<Msg>{div!["not_found"]}"###;
-    const HOME: &str = r###"fn home(theme : &Theme) -> Node<Msg>{div!["home"]}"###;
+    const NOT_FOUND: &str = r###"// -> /not_found
+fn not_found(model : &Model) -> Node<Msg>{div!["not_found"]}"###;
+    const HOME: &str = r###"// -> /
+fn home(theme : &Theme) -> Node<Msg>{div!["home"]}"###;
 
     #[test]
     fn test_get_local_views() {
@@ -129,6 +646,8 @@ mod test {
                     children: false,
                     id_param: false,
                     query: false,
+                    as_path: None,
+                    default_route: false,
                     content_to_load: FORBIDDEN_VIEW.to_string(),
                 },
             },
@@ -144,6 +663,8 @@ mod test {
                     children: false,
                     id_param: false,
                     query: false,
+                    as_path: None,
+                    default_route: true,
                     content_to_load: NOT_FOUND.to_string(),
                 },
             },
@@ -160,6 +681,8 @@ mod test {
                     children: false,
                     id_param: false,
                     query: false,
+                    as_path: Some("".to_string()),
+                    default_route: false,
                     content_to_load: HOME.to_string(),
                 },
             },
@@ -198,11 +721,11 @@ mod test {
 
         assert_eq!(
             directory_module.meta().filepath(),
-            "./my_app/src/pages/mod.rs"
+            Path::new("./my_app/src/pages/mod.rs")
         );
         assert_eq!(
             directory_module.meta().import_file_location(),
-            "./my_app/src/lib.rs"
+            Path::new("./my_app/src/lib.rs")
         );
         assert_eq!(directory_module.meta().mod_import(), "mod pages;");
     }
@@ -241,8 +764,8 @@ mod test {
         assert_eq!(dashboard.is_some(), true);
         assert_eq!(admin.is_some(), true);
 
-        assert_eq!(dir.meta().filepath(), "./src/my_app/pages/mod.rs");
-        assert_eq!(dir.meta().import_file_location(), "./src/my_app/lib.rs");
+        assert_eq!(dir.meta().filepath(), Path::new("./src/my_app/pages/mod.rs"));
+        assert_eq!(dir.meta().import_file_location(), Path::new("./src/my_app/lib.rs"));
         assert_eq!(dir.meta().mod_import(), "mod pages;");
     }
 
@@ -280,8 +803,214 @@ mod test {
         assert_eq!(dashboard.is_some(), true);
         assert_eq!(admin.is_some(), true);
 
-        assert_eq!(dir.meta().filepath(), "./src/my_app/lib.rs");
-        assert_eq!(dir.meta().import_file_location(), "");
+        assert_eq!(dir.meta().filepath(), Path::new("./src/my_app/lib.rs"));
+        assert_eq!(dir.meta().import_file_location(), Path::new(""));
         assert_eq!(dir.meta().mod_import(), "");
     }
+
+    #[test]
+    fn test_modules_paths() {
+        let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+        let routes_enum = find_routes(&parsed_file,).unwrap();
+        let model = find_model(&parsed_file,).unwrap();
+        let paths = |root_path_file: &str, target_file_path: &str| {
+            let content = SeedContent::new(
+                routes_enum.clone(),
+                model.clone(),
+                root_path_file,
+                target_file_path,
+            );
+            let login = content.modules().get("login",).unwrap().meta().clone();
+            (
+                content.parent_module.folder_path().to_path_buf(),
+                content.parent_module.meta().import_file_location().to_path_buf(),
+                login.filepath().to_path_buf(),
+                login.import_file_location().to_path_buf(),
+            )
+        };
+
+        // bare `lib.rs` from inside src
+        let (folder, lib, login, pages_mod,) = paths(".", "lib.rs",);
+        assert_eq!(folder, Path::new("./pages"));
+        assert_eq!(lib, Path::new("lib.rs"));
+        assert_eq!(login, Path::new("./pages/login.rs"));
+        assert_eq!(pages_mod, Path::new("./pages/mod.rs"));
+
+        // trailing and double slashes
+        let (folder, lib, login, pages_mod,) = paths("./my_app/src/", "./my_app//src/lib.rs",);
+        assert_eq!(folder, Path::new("./my_app/src/pages"));
+        assert_eq!(lib, Path::new("./my_app/src/lib.rs"));
+        assert_eq!(login, Path::new("./my_app/src/pages/login.rs"));
+        assert_eq!(pages_mod, Path::new("./my_app/src/pages/mod.rs"));
+
+        // absolute path
+        let (_, lib, login, _,) = paths("/my_app/src/../src", "/my_app/src/lib.rs",);
+        assert_eq!(lib, Path::new("/my_app/src/lib.rs"));
+        assert_eq!(login, Path::new("/my_app/src/pages/login.rs"));
+    }
+
+    #[test]
+    fn test_content_visibilities() {
+        let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+        let visibilities = Visibilities {
+            modules: Visibility::Public,
+            folder_modules: Visibility::Crate,
+            tea: Visibility::Crate,
+            guards: Visibility::Public,
+            views: Visibility::Crate,
+        };
+        let content = SeedContent::new_with_settings(
+            find_routes(&parsed_file,).unwrap(),
+            find_model(&parsed_file,).unwrap(),
+            "./src",
+            "./src/lib.rs",
+            &ContentSettings {
+                visibilities,
+                ..ContentSettings::default()
+            },
+        );
+
+        assert_eq!(content.parent_module.meta().mod_import(), "pub mod pages;");
+        let login = content.modules.get("login",).unwrap();
+        assert_eq!(login.meta().mod_import(), "pub(crate) mod login;");
+        assert!(login.init().contains(" pub(crate) fn init("));
+        assert!(login.model().contains(
+            "#[derive(Default)]\n pub(crate) struct Model {\n    pub(crate) query: \
+             IndexMap<String, String>,\n }"
+        ));
+        assert!(login.view().contains(" pub(crate) fn view("));
+        assert!(!login.update().contains("VISIBILITY"));
+
+        let guard = content.guards.get("admin_guard",).unwrap();
+        assert!(guard.content.contains("\npub fn admin_guard("));
+        assert!(guard.redirect.content.starts_with("pub(crate) fn forbidden_user("));
+        let not_found = content.local_views.get("not_found",).unwrap();
+        assert!(not_found.content.contains("\npub(crate) fn not_found("));
+    }
+
+    #[test]
+    fn test_routes_url_path() {
+        let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+        let model = find_model(&parsed_file,);
+        let routes_enum = find_routes(&parsed_file,);
+        let content = SeedContent::new(routes_enum.unwrap(), model.unwrap(), "", "",);
+        let routes = content.routes();
+
+        assert_eq!(routes.len(), 7);
+        assert_eq!(routes.get("Login").unwrap().url_path(), "/login?query");
+        assert_eq!(routes.get("Dashboard").unwrap().url_path(), "/dashboard/*");
+        assert_eq!(routes.get("Admin").unwrap().url_path(), "/admin/:id/*");
+        assert_eq!(routes.get("NotFound").unwrap().url_path(), "/not_found");
+        assert_eq!(routes.get("Home").unwrap().url_path(), "/");
+        assert!(routes.get("NotFound").unwrap().default_route);
+        assert!(content.route_issues().is_empty());
+    }
+
+    #[test]
+    fn test_scope_issues() {
+        let file = _FILE_WITH_ROUTES_AND_MODEL
+            .replace("logged_user => admin_guard", "loged_user => admin_guard",)
+            .replace("theme => home", "session => home",);
+        let parsed_file = syn::parse_file(&file,).unwrap();
+        let model = find_model(&parsed_file,);
+        let routes_enum = find_routes(&parsed_file,);
+        let content =
+            SeedContent::new(routes_enum.unwrap(), model.unwrap(), "./src", "./src/lib.rs",);
+
+        let issues = content.route_issues();
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().all(|issue| !issue.is_error()));
+        match &issues[0] {
+            RouteIssue::ScopeNotFound {
+                route,
+                attribute,
+                location,
+                suggestion,
+                ..
+            } => {
+                assert_eq!(route, "Admin");
+                assert_eq!(attribute, "guard");
+                let location = location.as_ref().unwrap();
+                assert_eq!(location.file, Path::new("./src/lib.rs"));
+                assert!(issues[0].to_string().starts_with(&format!(
+                    "./src/lib.rs:{}:{} : scope",
+                    location.line, location.column
+                )));
+                assert_eq!(suggestion.as_deref(), Some("logged_user"));
+            },
+            issue => panic!("unexpected issue {}", issue),
+        }
+        assert!(issues[0].to_string().contains(
+            "scope \"loged_user\" of #[guard] on Admin not found on Model, did you mean \
+             \"logged_user\" ? Model is injected instead"
+        ));
+        assert!(issues[1].to_string().contains("scope \"session\" of #[view] on Home"));
+        assert!(!issues[1].to_string().contains("did you mean"));
+    }
+
+    #[test]
+    fn test_guard_conflicts() {
+        let file = _FILE_WITH_ROUTES_AND_MODEL.replace(
+            "logged_user => admin_guard => forbidden_user",
+            "logged_user => guard => forbidden_user",
+        );
+        let parsed_file = syn::parse_file(&file,).unwrap();
+        let model = find_model(&parsed_file,);
+        let routes_enum = find_routes(&parsed_file,);
+        let content = SeedContent::new(routes_enum.unwrap(), model.unwrap(), "", "",);
+
+        let issues = content.route_issues();
+        assert_eq!(issues, vec![RouteIssue::GuardConflict {
+            guard: "guard".to_string(),
+            declarations: vec![
+                ("Settings".to_string(), " => guard => forbidden".to_string()),
+                ("Admin".to_string(), "logged_user => guard => forbidden_user".to_string()),
+            ],
+        }]);
+        assert!(issues[0].is_error());
+        assert_eq!(
+            issues[0].to_string(),
+            "guard guard is declared differently on Settings \" => guard => forbidden\" and Admin \
+             \"logged_user => guard => forbidden_user\", use the same scope and redirect or rename \
+             the guard"
+        );
+    }
+
+    #[test]
+    fn test_route_issues() {
+        let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_COLLISIONS,).unwrap();
+        let model = find_model(&parsed_file,);
+        let routes_enum = find_routes(&parsed_file,);
+        let content = SeedContent::new(routes_enum.unwrap(), model.unwrap(), "", "",);
+
+        let issues = content.route_issues();
+
+        assert_eq!(
+            issues,
+            vec![
+                RouteIssue::MultipleDefaultRoutes(vec![
+                    "NotFound".to_string(),
+                    "Home".to_string()
+                ]),
+                RouteIssue::PathCollision {
+                    path: "login".to_string(),
+                    routes: vec!["Login".to_string(), "SignIn".to_string()],
+                },
+            ]
+        );
+        assert!(issues.iter().all(|issue| issue.is_error()));
+
+        let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_AND_MODEL_WITHOUT_PAGES,).unwrap();
+        let model = find_model(&parsed_file,);
+        let routes_enum = find_routes(&parsed_file,);
+        let mut routes_enum = routes_enum.unwrap();
+        routes_enum
+            .variants
+            .iter_mut()
+            .for_each(|v| v.attrs.retain(|a| !a.path.is_ident("default_route")));
+        let content = SeedContent::new(routes_enum, model.unwrap(), "", "",);
+
+        assert_eq!(content.route_issues(), vec![RouteIssue::MissingDefaultRoute]);
+        assert!(!content.route_issues()[0].is_error());
+    }
 }
@@ -0,0 +1,350 @@
+//! List, detail and edit pages over a struct of the crate from
+//! `#[crud = "models::user::User"]`.
+
+use crate::{
+    content::{
+        module::{
+            model::{get_model, get_model_init, get_payload_fields},
+            nested::{get_nested_view, get_payload},
+            templates::{
+                crud::{
+                    _CRUD_CELL_TEMPLATE, _CRUD_CHANGED_MESSAGE_TEMPLATE, _CRUD_CHANGED_TEMPLATE,
+                    _CRUD_FIND_BY_ID, _CRUD_FIND_BY_POSITION, _CRUD_HEADER_TEMPLATE,
+                    _CRUD_INDEX_BY_ID, _CRUD_INDEX_BY_POSITION, _CRUD_INIT_TEMPLATE,
+                    _CRUD_INPUT_TEMPLATE, _CRUD_MESSAGE_TEMPLATE, _CRUD_PARSED_CHANGED_TEMPLATE,
+                    _CRUD_ROUTES_TEMPLATE, _CRUD_ROW_TEMPLATE, _CRUD_UPDATE_TEMPLATE,
+                    _CRUD_USE_TEMPLATE, _CRUD_VIEWS_TEMPLATE,
+                },
+                init::_INIT_COMMENT,
+                message::_MESSAGE_COMMENT,
+                model::_MODEL_COMMENT,
+                route::_ROUTES_COMMENT,
+                update::_UPDATE_COMMENT,
+                view::_VIEW_COMMENT,
+            },
+            SeedModule,
+        },
+        ContentSettings, Location, RouteIssue, SeedRoute,
+    },
+    parser::route::get_crud_attribute,
+};
+use convert_case::{Case, Casing};
+use indexmap::map::IndexMap;
+use std::path::Path;
+use syn::{export::ToTokens, ItemEnum, ItemStruct};
+
+/// The types a form input can edit from its text.
+const SIMPLE_TYPES: [&str; 17] = [
+    "String", "bool", "char", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32",
+    "u64", "u128", "usize", "f32", "f64",
+];
+
+/// Replace the content of the modules of the variants with `#[crud]` by the
+/// pages over their struct. The modules stay as generated when the struct is
+/// not found or the variant has no nested routes.
+pub fn scaffold_cruds(
+    routes_enum: &ItemEnum,
+    modules: &mut IndexMap<String, SeedModule,>,
+    settings: &ContentSettings,
+    file: &Path,
+) -> Vec<RouteIssue,> {
+    let mut issues: Vec<RouteIssue,> = Vec::new();
+    for v in routes_enum.variants.iter() {
+        let path = match get_crud_attribute(v.attrs.iter(),) {
+            Some(path,) => path,
+            None => continue,
+        };
+        let module = match modules.get_mut(&v.ident.to_string().to_case(Case::Snake,),) {
+            Some(module,) => module,
+            None => continue,
+        };
+        let route = match module.origin_route() {
+            Some(route,) if route.nested || route.children => route.clone(),
+            _ => {
+                issues.push(RouteIssue::CrudWithoutNestedRoutes {
+                    route: v.ident.to_string(),
+                    location: Location::of_attribute(file, "crud", v.attrs.iter(),),
+                },);
+                continue;
+            },
+        };
+        match settings.index.resolve_struct(&[], &path,) {
+            Some((module_path, item,),) => {
+                let item_path = ["crate".to_string(),]
+                    .iter()
+                    .chain(module_path.iter(),)
+                    .chain([item.ident.to_string(),].iter(),)
+                    .cloned()
+                    .collect::<Vec<String,>>()
+                    .join("::",);
+                scaffold_crud(module, &route, item, &item_path, settings,);
+            },
+            None => issues.push(RouteIssue::CrudStructNotFound {
+                route: v.ident.to_string(),
+                path,
+                location: Location::of_attribute(file, "crud", v.attrs.iter(),),
+            },),
+        }
+    }
+    issues
+}
+
+/// The url of a nested route of the module, after the segment and the id of
+/// the route of the parent since `to_url` only gives the nested part.
+fn get_url(route: &SeedRoute, nested: &str,) -> String {
+    let segment = route.path_segment();
+    let prefix = if segment.is_empty() {
+        String::new()
+    } else {
+        format!("/{}", segment)
+    };
+    if route.id_param {
+        format!("format!(\"{}/{{}}{{}}\", model.id, {}.to_url())", prefix, nested)
+    } else if prefix.is_empty() {
+        format!("{}.to_url()", nested)
+    } else {
+        format!("format!(\"{}{{}}\", {}.to_url())", prefix, nested)
+    }
+}
+
+/// Write the Routes, Model, Msg, init, update and views of the pages over the
+/// struct.
+pub fn scaffold_crud(
+    module: &mut SeedModule,
+    route: &SeedRoute,
+    item: &ItemStruct,
+    item_path: &str,
+    settings: &ContentSettings,
+) {
+    let tea = settings.visibilities.tea;
+    let item_name = item.ident.to_string();
+    let fields = get_simple_fields(item,);
+    let has_id = fields.iter().any(|(name, _,)| name == "id",);
+    let edited = fields.iter().filter(|(name, _,)| name != "id",);
+
+    let routes = _CRUD_ROUTES_TEMPLATE.to_string();
+    let routes_enum = syn::parse_str::<ItemEnum,>(&tea.apply(&routes,),)
+        .expect("the crud routes template should parse",);
+
+    let query = module.query().clone();
+    let (payload, nested,) = if route.nested {
+        ("nested: &Routes".to_string(), "nested",)
+    } else {
+        (get_payload(route, query.as_ref(),), "children",)
+    };
+    let init = _CRUD_INIT_TEMPLATE
+        .replace("PAYLOAD", &payload,)
+        .replace("NESTED", nested,)
+        .replace("MODEL", &get_model_init(route, query.as_ref(), false,),)
+        .replace("TEMPLATE", &route.name,);
+
+    let mut model_fields: Vec<(String, String,),> = get_payload_fields(route, query.as_ref(),)
+        .into_iter()
+        .map(|(name, ty, _,)| (name, ty,),)
+        .collect();
+    model_fields.extend(vec![
+        ("items".to_string(), format!("Vec<{}>", item_name),),
+        ("selected".to_string(), "Option<String>".to_string(),),
+        ("form".to_string(), item_name.clone(),),
+        ("error".to_string(), "Option<String>".to_string(),),
+    ],);
+    let model = format!(
+        "{}{}",
+        _CRUD_USE_TEMPLATE.replace("ITEM_PATH", item_path,),
+        get_model(&model_fields, query.as_ref(),)
+    );
+
+    let mut changed_messages = String::new();
+    let mut changed_arms = String::new();
+    let mut inputs = String::new();
+    for (name, ty,) in edited {
+        let variant = format!("{}Changed", name.to_case(Case::Pascal,));
+        changed_messages.push_str(&_CRUD_CHANGED_MESSAGE_TEMPLATE.replace("VARIANT", &variant,),);
+        let arm = if ty == "String" {
+            _CRUD_CHANGED_TEMPLATE
+        } else {
+            _CRUD_PARSED_CHANGED_TEMPLATE
+        };
+        changed_arms.push_str(&arm.replace("VARIANT", &variant,).replace("FIELD", name,),);
+        inputs.push_str(
+            &_CRUD_INPUT_TEMPLATE
+                .replace("LABEL", &name.to_case(Case::Title,),)
+                .replace("FIELD", name,)
+                .replace("VARIANT", &variant,),
+        );
+    }
+    let msg = _CRUD_MESSAGE_TEMPLATE
+        .replace("CHANGED", &changed_messages,)
+        .replace("ITEM", &item_name,);
+    let (index, find,) = if has_id {
+        (_CRUD_INDEX_BY_ID, _CRUD_FIND_BY_ID,)
+    } else {
+        (_CRUD_INDEX_BY_POSITION, _CRUD_FIND_BY_POSITION,)
+    };
+    let update = _CRUD_UPDATE_TEMPLATE
+        .replace("CHANGED", &changed_arms,)
+        .replace("INDEX", index,)
+        .replace("FIND", find,)
+        .replace("ITEM", &item_name,);
+
+    let mut headers = String::new();
+    let mut cells = String::new();
+    let mut rows = String::new();
+    for (name, _,) in fields.iter() {
+        let label = name.to_case(Case::Title,);
+        headers.push_str(&_CRUD_HEADER_TEMPLATE.replace("LABEL", &label,),);
+        cells.push_str(&_CRUD_CELL_TEMPLATE.replace("FIELD", name,),);
+        rows.push_str(&_CRUD_ROW_TEMPLATE.replace("LABEL", &label,).replace("FIELD", name,),);
+    }
+    let (items, key,) = if has_id {
+        ("model.items.iter().map(|item|", "item.id.to_string()",)
+    } else {
+        ("model.items.iter().enumerate().map(|(index, item,)|", "index.to_string()",)
+    };
+    let views = _CRUD_VIEWS_TEMPLATE
+        .replace("HEADERS", &headers,)
+        .replace("CELLS", &cells,)
+        .replace("ROWS", &rows,)
+        .replace("INPUTS", &inputs,)
+        .replace("ITEMS", items,)
+        .replace("DETAIL_URL", &get_url(route, &format!("Routes::Detail {{ id: {} }}", key),),)
+        .replace("EDIT_URL", &get_url(route, "Routes::Edit { id }",),);
+    let model_struct = syn::parse_str::<ItemStruct,>("struct Model {}",).unwrap();
+    let view = get_nested_view(&routes_enum, &model_struct, settings,);
+
+    module
+        .set_routes(format!("{} {}", _ROUTES_COMMENT, tea.apply(&routes)),)
+        .set_init(format!("{} {}", _INIT_COMMENT, tea.apply(&init)),)
+        .set_model(format!("{} {}", _MODEL_COMMENT, tea.apply(&model)),)
+        .set_msg(format!("{} {}", _MESSAGE_COMMENT, tea.apply(&msg)),)
+        .set_update(format!("{} {}", _UPDATE_COMMENT, tea.apply(&update)),)
+        .set_view(format!(
+            "{}// -> {}\n{}{}",
+            _VIEW_COMMENT,
+            route.url_path(),
+            tea.apply(&view),
+            settings.visibilities.views.apply(&views,)
+        ),)
+        .set_crud(Some(item_path.to_string(),),);
+}
+
+/// The named fields of the struct a form can edit, as name and type.
+pub fn get_simple_fields(item: &ItemStruct,) -> Vec<(String, String,),> {
+    item.fields
+        .iter()
+        .filter_map(|f| {
+            let ty = f.ty.to_token_stream().to_string();
+            if SIMPLE_TYPES.contains(&ty.as_str(),) {
+                Some((f.ident.as_ref()?.to_string(), ty,),)
+            } else {
+                None
+            }
+        },)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        content::{scope::CrateIndex, ContentSettings, RouteIssue, SeedContent},
+        find_model, find_routes,
+    };
+
+    const CRUD_FILE: &str = r###"
+mod models {
+    pub mod user {
+        #[derive(Clone, Default)]
+        pub struct User {
+            pub id: u32,
+            pub user_name: String,
+            pub roles: Vec<String>,
+        }
+    }
+}
+
+pub struct Model {}
+
+#[derive(Debug, PartialEq, Clone, RoutingModules)]
+#[modules_path = "pages"]
+pub enum Routes {
+    #[crud = "models::user::User"]
+    Users(pages::users::Routes),
+    #[crud = "models::user::Usr"]
+    Admins(pages::admins::Routes),
+    #[crud = "models::user::User"]
+    Projects {
+        id: String,
+        children: pages::projects::Routes,
+    },
+    #[crud = "models::user::User"]
+    #[default_route]
+    Profile,
+}
+"###;
+
+    #[test]
+    fn test_scaffold_cruds() {
+        let parsed_file = syn::parse_file(CRUD_FILE,).unwrap();
+        let mut index = CrateIndex::default();
+        index.add_module(Vec::new(), &parsed_file.items,);
+        let content = SeedContent::new_with_settings(
+            find_routes(&parsed_file,).unwrap(),
+            find_model(&parsed_file,).unwrap(),
+            "",
+            "",
+            &ContentSettings {
+                index,
+                ..ContentSettings::default()
+            },
+        );
+
+        let issues = content.route_issues();
+        assert_eq!(issues.len(), 2);
+        assert!(matches!(
+            &issues[0],
+            RouteIssue::CrudStructNotFound { route, .. } if route == "Admins"
+        ));
+        assert!(matches!(
+            &issues[1],
+            RouteIssue::CrudWithoutNestedRoutes { route, .. } if route == "Profile"
+        ));
+        assert!(issues.iter().all(|issue| !issue.is_error()));
+        assert!(content.modules()["admins"].crud().is_none());
+
+        let users = &content.modules()["users"];
+        assert_eq!(users.crud().as_deref(), Some("crate::models::user::User"));
+        let sections = [
+            users.routes(),
+            users.init(),
+            users.model(),
+            users.msg(),
+            users.update(),
+            users.view(),
+        ];
+        for section in sections.iter() {
+            assert!(syn::parse_file(section,).is_ok(), "{}", section);
+        }
+        assert!(users.model().contains("use crate::models::user::User;"));
+        assert!(users.model().contains("    pub items: Vec<User>,\n"));
+        assert!(users.msg().contains("    UserNameChanged(String),\n"));
+        assert!(!users.msg().contains("IdChanged"));
+        assert!(!users.msg().contains("RolesChanged"));
+        assert!(users
+            .update()
+            .contains("Msg::UserNameChanged(value,) => model.form.user_name = value,"));
+        assert!(users.view().contains("        Routes::Detail { .. } => detail(model,),\n"));
+        assert!(users.view().contains("th![\"User Name\"],"));
+        assert!(users.view().contains(
+            "At::Href => format!(\"/users{}\", Routes::Detail { id: item.id.to_string() }.to_url())"
+        ));
+        assert!(users
+            .view()
+            .contains("At::Href => format!(\"/users{}\", Routes::Edit { id }.to_url())"));
+
+        let projects = &content.modules()["projects"];
+        assert!(syn::parse_file(projects.view(),).is_ok(), "{}", projects.view());
+        assert!(projects.view().contains(
+            "At::Href => format!(\"/projects/{}{}\", model.id, Routes::Detail { id: item.id.to_string() }.to_url())"
+        ));
+    }
+}
@@ -0,0 +1,259 @@
+//! Detail page loading its data from the id of the route with
+//! `#[fetch = "models::user::User"]`.
+
+use crate::{
+    content::{
+        module::{
+            crud::get_simple_fields,
+            model::{get_model, get_model_init, get_payload_fields},
+            nested::get_payload,
+            templates::{
+                fetch::{
+                    _FETCH_DEBUG_ROW_TEMPLATE, _FETCH_INIT_TEMPLATE, _FETCH_MESSAGE_TEMPLATE,
+                    _FETCH_ROW_TEMPLATE, _FETCH_STATE_USE_TEMPLATE, _FETCH_UPDATE_TEMPLATE,
+                    _FETCH_USE_TEMPLATE, _FETCH_VIEW_TEMPLATE,
+                },
+                init::_INIT_COMMENT,
+                message::_MESSAGE_COMMENT,
+                model::_MODEL_COMMENT,
+                update::_UPDATE_COMMENT,
+                view::_VIEW_COMMENT,
+            },
+            SeedModule,
+        },
+        ContentSettings, Location, RouteIssue, SeedRoute,
+    },
+    parser::route::get_fetch_attribute,
+};
+use convert_case::{Case, Casing};
+use indexmap::map::IndexMap;
+use std::path::Path;
+use syn::{ItemEnum, ItemStruct};
+
+/// Replace the init, Model, Msg, update and view of the modules of the
+/// variants with `#[fetch]` by the ones loading the type from the id. A type
+/// not found in the crate is written as given and shown with `Debug`. A route
+/// with nested routes keeps its dispatch and is reported.
+pub fn scaffold_fetches(
+    routes_enum: &ItemEnum,
+    modules: &mut IndexMap<String, SeedModule,>,
+    settings: &ContentSettings,
+    file: &Path,
+) -> Vec<RouteIssue,> {
+    let mut issues: Vec<RouteIssue,> = Vec::new();
+    for v in routes_enum.variants.iter() {
+        let path = match get_fetch_attribute(v.attrs.iter(),) {
+            Some(path,) => path,
+            None => continue,
+        };
+        let module = match modules.get_mut(&v.ident.to_string().to_case(Case::Snake,),) {
+            Some(module,) if module.crud().is_none() => module,
+            _ => continue,
+        };
+        let route = match module.origin_route() {
+            Some(route,) if route.nested || route.children => {
+                issues.push(RouteIssue::FetchWithNestedRoutes {
+                    route: v.ident.to_string(),
+                    location: Location::of_attribute(file, "fetch", v.attrs.iter(),),
+                },);
+                continue;
+            },
+            Some(route,) if route.id_param => route.clone(),
+            _ => {
+                issues.push(RouteIssue::FetchWithoutId {
+                    route: v.ident.to_string(),
+                    location: Location::of_attribute(file, "fetch", v.attrs.iter(),),
+                },);
+                continue;
+            },
+        };
+        match settings.index.resolve_struct(&[], &path,) {
+            Some((module_path, item,),) => {
+                let item_path = ["crate".to_string(),]
+                    .iter()
+                    .chain(module_path.iter(),)
+                    .chain([item.ident.to_string(),].iter(),)
+                    .cloned()
+                    .collect::<Vec<String,>>()
+                    .join("::",);
+                scaffold_fetch(module, &route, &item_path, Some(item,), settings,);
+            },
+            None => scaffold_fetch(module, &route, &path, None, settings,),
+        }
+    }
+    issues
+}
+
+/// Write the init requesting the type, the Model with its loading state and
+/// the view of each state. The struct found in the crate is imported from
+/// `item_path` and its fields are shown, the `State` is the one of the target
+/// file.
+pub fn scaffold_fetch(
+    module: &mut SeedModule,
+    route: &SeedRoute,
+    item_path: &str,
+    item: Option<&ItemStruct,>,
+    settings: &ContentSettings,
+) {
+    let tea = settings.visibilities.tea;
+    let (item_name, import,) = match item {
+        Some(item,) => (
+            item.ident.to_string(),
+            _FETCH_USE_TEMPLATE.replace("ITEM_PATH", item_path,),
+        ),
+        None => (item_path.to_string(), String::new(),),
+    };
+    let item_name = item_name.as_str();
+    let name = item_name
+        .rsplit("::",)
+        .next()
+        .unwrap_or(item_name,)
+        .to_case(Case::Snake,);
+    let query = module.query().clone();
+
+    let init = _FETCH_INIT_TEMPLATE
+        .replace("PAYLOAD", &get_payload(route, query.as_ref(),),)
+        .replace("MODEL", &get_model_init(route, query.as_ref(), true,),)
+        .replace("TEMPLATE", &route.name,)
+        .replace("ITEM", item_name,)
+        .replace("URL", &route.name.to_case(Case::Snake,),);
+
+    let mut fields: Vec<(String, String,),> = get_payload_fields(route, query.as_ref(),)
+        .into_iter()
+        .map(|(name, ty, _,)| (name, ty,),)
+        .collect();
+    fields.push((name.clone(), format!("State<{}>", item_name),),);
+    let model = format!(
+        "{}{}{}",
+        import,
+        _FETCH_STATE_USE_TEMPLATE,
+        get_model(&fields, query.as_ref(),)
+    );
+
+    let rows: String = match item {
+        Some(item,) => get_simple_fields(item,)
+            .iter()
+            .map(|(field, _,)| {
+                _FETCH_ROW_TEMPLATE
+                    .replace("LABEL", &field.to_case(Case::Title,),)
+                    .replace("FIELD", field,)
+            },)
+            .collect(),
+        None => _FETCH_DEBUG_ROW_TEMPLATE.to_string(),
+    };
+    let view = _FETCH_VIEW_TEMPLATE.replace("ROWS", &rows,).replace("NAME", &name,);
+
+    module
+        .set_init(format!("{} {}", _INIT_COMMENT, tea.apply(&init)),)
+        .set_model(format!("{} {}", _MODEL_COMMENT, tea.apply(&model)),)
+        .set_msg(format!(
+            "{} {}",
+            _MESSAGE_COMMENT,
+            tea.apply(&_FETCH_MESSAGE_TEMPLATE.replace("ITEM", item_name,))
+        ),)
+        .set_update(format!(
+            "{} {}",
+            _UPDATE_COMMENT,
+            tea.apply(&_FETCH_UPDATE_TEMPLATE.replace("NAME", &name,))
+        ),)
+        .set_view(format!(
+            "{}// -> {}\n{}",
+            _VIEW_COMMENT,
+            route.url_path(),
+            tea.apply(&view)
+        ),)
+        .set_fetch(Some(item_path.to_string(),),);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        content::{scope::CrateIndex, ContentSettings, RouteIssue, SeedContent},
+        find_model, find_routes,
+    };
+
+    const FETCH_FILE: &str = r###"
+mod models {
+    #[derive(Debug, Deserialize)]
+    pub struct User {
+        pub id: u32,
+        pub user_name: String,
+    }
+}
+
+pub struct Model {}
+
+#[derive(Debug, PartialEq, Clone, RoutingModules)]
+#[modules_path = "pages"]
+pub enum Routes {
+    #[fetch = "models::User"]
+    User { id: String },
+    #[fetch = "shared::Report"]
+    Report { id: String, query: IndexMap<String, String> },
+    #[fetch = "models::User"]
+    Project {
+        id: String,
+        children: pages::project::Routes,
+    },
+    #[fetch = "models::User"]
+    #[default_route]
+    Profile,
+}
+"###;
+
+    #[test]
+    fn test_scaffold_fetches() {
+        let parsed_file = syn::parse_file(FETCH_FILE,).unwrap();
+        let mut index = CrateIndex::default();
+        index.add_module(Vec::new(), &parsed_file.items,);
+        let content = SeedContent::new_with_settings(
+            find_routes(&parsed_file,).unwrap(),
+            find_model(&parsed_file,).unwrap(),
+            "",
+            "",
+            &ContentSettings {
+                index,
+                ..ContentSettings::default()
+            },
+        );
+
+        let issues = content.route_issues();
+        assert_eq!(issues.len(), 2);
+        assert!(matches!(
+            &issues[0],
+            RouteIssue::FetchWithNestedRoutes { route, .. } if route == "Project"
+        ));
+        assert!(matches!(
+            &issues[1],
+            RouteIssue::FetchWithoutId { route, .. } if route == "Profile"
+        ));
+        assert!(issues.iter().all(|issue| !issue.is_error()));
+        assert!(content.modules()["project"].fetch().is_none());
+        assert!(content.modules()["profile"].fetch().is_none());
+
+        let state = content.fetch_state().as_ref().unwrap();
+        assert!(syn::parse_file(state,).is_ok(), "{}", state);
+        assert!(state.contains("enum State<T,> {"));
+
+        let user = &content.modules()["user"];
+        assert_eq!(user.fetch().as_deref(), Some("crate::models::User"));
+        for section in [user.init(), user.model(), user.msg(), user.update(), user.view(),].iter() {
+            assert!(syn::parse_file(section,).is_ok(), "{}", section);
+        }
+        assert!(user.init().contains("orders.perform_cmd(async move {"));
+        assert!(user.init().contains("async fn request(id: String,) -> fetch::Result<User,> {"));
+        assert!(user.model().contains("use crate::models::User;"));
+        assert!(user.model().contains("use crate::State;"));
+        assert!(!user.model().contains("enum State"));
+        assert!(user.model().contains("    pub user: State<User>,\n"));
+        assert!(user.msg().contains("    Fetched(fetch::Result<User,>,),\n"));
+        assert!(user.view().contains("p![format!(\"User Name : {}\", user.user_name)],"));
+
+        let report = &content.modules()["report"];
+        assert_eq!(report.fetch().as_deref(), Some("shared::Report"));
+        assert!(report.init().contains("query: &IndexMap<String, String>,"));
+        assert!(!report.model().contains("use shared"));
+        assert!(report.model().contains("    pub report: State<shared::Report>,\n"));
+        assert!(report.view().contains("format!(\"{:?}\", report),"));
+    }
+}
@@ -1,7 +1,8 @@
 //! Manage module imports to write for Seed content and files to write with and
 //! without Folder.
 
-use crate::content::module::ModuleMeta;
+use crate::content::{module::ModuleMeta, visibility::Visibility};
+use std::path::{Path, PathBuf};
 
 const ERROR_WHEN_PATH_EMPTY: &str = "Cannot have empty path for directory";
 const ERROR_WHEN_MULTIPLE_FOLDERS_NOT_SUPPORTED: &str =
@@ -12,7 +13,7 @@ const ERROR_WHEN_MULTIPLE_FOLDERS_NOT_SUPPORTED: &str =
 #[derive(Debug, Clone)]
 pub struct ImportModule {
     meta: ModuleMeta,
-    folder_path: String,
+    folder_path: PathBuf,
     name: String,
     /// List of imports.
     pub imports_content: Vec<String,>,
@@ -38,7 +39,7 @@ impl ImportModule {
         &self.imports_content
     }
 
-    pub fn folder_path(&self,) -> &str {
+    pub fn folder_path(&self,) -> &Path {
         &self.folder_path
     }
 
@@ -50,11 +51,13 @@ impl ImportModule {
         content
     }
 
-    /// Create a new import module.
+    /// Create a new import module, declared with the given visibility in the
+    /// target file.
     pub fn new_folder_module(
         directory: &str,
-        current_path: &str,
-        target_file_path: &str,
+        current_path: &Path,
+        target_file_path: &Path,
+        visibility: Visibility,
     ) -> Result<ImportModule, String,> {
         if directory.contains('/',) {
             return Result::Err(ERROR_WHEN_MULTIPLE_FOLDERS_NOT_SUPPORTED.to_string(),);
@@ -64,11 +67,11 @@ impl ImportModule {
         }
         Ok(ImportModule {
             name: directory.to_string(),
-            folder_path: format!("{}/{}", current_path, directory),
+            folder_path: current_path.join(directory,),
             meta: ModuleMeta {
-                filepath: format!("{}/{}/mod.rs", current_path, directory),
-                mod_import: format!("mod {};", directory),
-                import_file_location: target_file_path.to_string(),
+                filepath: current_path.join(directory,).join("mod.rs",),
+                mod_import: format!("{}mod {};", visibility.prefix(), directory),
+                import_file_location: target_file_path.to_path_buf(),
             },
             imports_content: vec![],
             imports_names: vec![],
@@ -78,17 +81,17 @@ impl ImportModule {
 
     /// Mark target file as module for imports.
     pub fn new_target_file_module(
-        current_path: &str,
-        target_file_path: &str,
+        current_path: &Path,
+        target_file_path: &Path,
     ) -> Result<ImportModule, String,> {
         Ok(ImportModule {
             // todo need to fix later
             name: "".parse().unwrap(),
-            folder_path: current_path.to_string(),
+            folder_path: current_path.to_path_buf(),
             meta: ModuleMeta {
-                filepath: target_file_path.to_string(),
+                filepath: target_file_path.to_path_buf(),
                 mod_import: "".to_string(),
-                import_file_location: "".to_string(),
+                import_file_location: PathBuf::new(),
             },
             imports_content: vec![],
             imports_names: vec![],
@@ -98,35 +101,57 @@ impl ImportModule {
 }
 #[cfg(test)]
 mod test {
-    use crate::content::module::import::{
-        ImportModule, ERROR_WHEN_MULTIPLE_FOLDERS_NOT_SUPPORTED, ERROR_WHEN_PATH_EMPTY,
+    use crate::content::{
+        module::import::{
+            ImportModule, ERROR_WHEN_MULTIPLE_FOLDERS_NOT_SUPPORTED, ERROR_WHEN_PATH_EMPTY,
+        },
+        visibility::Visibility,
     };
+    use std::path::Path;
 
     #[test]
     fn get_pages() {
-        let import_result =
-            ImportModule::new_folder_module("pages", "./my_app/src", "./my_app/src/lib.rs",);
+        let import_result = ImportModule::new_folder_module(
+            "pages",
+            Path::new("./my_app/src",),
+            Path::new("./my_app/src/lib.rs",),
+            Visibility::Private,
+        );
 
         let import = import_result.unwrap();
 
-        assert_eq!(import.meta().filepath(), "./my_app/src/pages/mod.rs");
-        assert_eq!(import.meta().import_file_location(), "./my_app/src/lib.rs");
+        assert_eq!(
+            import.meta().filepath(),
+            Path::new("./my_app/src/pages/mod.rs")
+        );
+        assert_eq!(
+            import.meta().import_file_location(),
+            Path::new("./my_app/src/lib.rs")
+        );
         assert_eq!(import.meta().mod_import(), "mod pages;");
-        assert_eq!(import.folder_path, "./my_app/src/pages");
+        assert_eq!(import.folder_path, Path::new("./my_app/src/pages"));
     }
 
     #[test]
     fn get_error_if_empty_path() {
-        let import_result =
-            ImportModule::new_folder_module("", "./my_app/src", "./my_app/src/lib.rs",);
+        let import_result = ImportModule::new_folder_module(
+            "",
+            Path::new("./my_app/src",),
+            Path::new("./my_app/src/lib.rs",),
+            Visibility::Private,
+        );
 
         assert_eq!(import_result.is_err(), true);
         assert_eq!(import_result.unwrap_err(), ERROR_WHEN_PATH_EMPTY)
     }
     #[test]
     fn get_error_if_multiple_folders() {
-        let import_result =
-            ImportModule::new_folder_module("pages/sub", "./my_app/src", "./my_app/src/lib.rs",);
+        let import_result = ImportModule::new_folder_module(
+            "pages/sub",
+            Path::new("./my_app/src",),
+            Path::new("./my_app/src/lib.rs",),
+            Visibility::Private,
+        );
 
         assert_eq!(import_result.is_err(), true);
         assert_eq!(
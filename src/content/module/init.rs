@@ -1,9 +1,12 @@
 //! Manage the build for fn init functions and the different arguments with it
 //! by reading from the parser.
-use crate::content::SeedRoute;
-
-use crate::content::module::templates::init::{
-    _NESTED_INIT_TEMPLATE, _PAYLOAD_INIT_TEMPLATE, _SIMPLE_INIT_TEMPLATE,
+use crate::content::{
+    module::{
+        model::get_model_init,
+        query::QueryStruct,
+        templates::init::{_NESTED_INIT_TEMPLATE, _PAYLOAD_INIT_TEMPLATE, _SIMPLE_INIT_TEMPLATE},
+    },
+    SeedRoute,
 };
 use proc_macro2::Ident;
 use syn::{punctuated::Iter, Field};
@@ -11,13 +14,17 @@ use syn::{punctuated::Iter, Field};
 pub fn get_init_for_unit_variant(ident: Ident,) -> (String, SeedRoute,) {
     let template = _SIMPLE_INIT_TEMPLATE;
     (
-        template.replace("TEMPLATE", ident.to_string().as_str(),),
+        template
+            .replace("TEMPLATE", ident.to_string().as_str(),)
+            .replace("MODEL", "Model::default()",),
         SeedRoute {
             name: ident.to_string(),
             nested: false,
             children: false,
             id_param: false,
             query: false,
+            as_path: None,
+            default_route: false,
             content_to_load: "".to_string(),
         },
     )
@@ -36,13 +43,17 @@ pub fn get_init_for_tuple_variant(ident: Ident, _: Iter<'_, Field,>,) -> (String
 
     let template = _NESTED_INIT_TEMPLATE;
     (
-        template.replace("TEMPLATE", ident.to_string().as_str(),),
+        template
+            .replace("TEMPLATE", ident.to_string().as_str(),)
+            .replace("MODEL", "Model::default()",),
         SeedRoute {
             name: ident.to_string(),
             nested: true,
             children: false,
             id_param: false,
             query: false,
+            as_path: None,
+            default_route: false,
             content_to_load: "".to_string(),
         },
     )
@@ -51,6 +62,7 @@ pub fn get_init_for_tuple_variant(ident: Ident, _: Iter<'_, Field,>,) -> (String
 pub fn get_init_for_init_struct_variant(
     ident: Ident,
     fields: Iter<'_, Field,>,
+    query: Option<&QueryStruct,>,
 ) -> (String, SeedRoute,) {
     let fields_to_extract = fields.clone();
 
@@ -66,21 +78,29 @@ pub fn get_init_for_init_struct_variant(
         .clone()
         .find(|f| f.ident.as_ref().unwrap() == "children",);
 
-    let payload = inject_variant_payload_in_function_call((id_param, query_parameters, children,),);
+    let mut payload =
+        inject_variant_payload_in_function_call((id_param, query_parameters, children,),);
+    if let Some(query,) = query {
+        payload = payload.replace("query: &IndexMap<String, String>", &query.argument(),);
+    }
 
+    let route = SeedRoute {
+        name: ident.to_string(),
+        nested: false,
+        children: children.is_some(),
+        id_param: id_param.is_some(),
+        query: query_parameters.is_some(),
+        as_path: None,
+        default_route: false,
+        content_to_load: "".to_string(),
+    };
     let template = _PAYLOAD_INIT_TEMPLATE;
     (
         template
             .replace("PAYLOAD", payload.as_str(),)
-            .replace("TEMPLATE", ident.to_string().as_str(),),
-        SeedRoute {
-            name: ident.to_string(),
-            nested: false,
-            children: children.is_some(),
-            id_param: id_param.is_some(),
-            query: query_parameters.is_some(),
-            content_to_load: "".to_string(),
-        },
+            .replace("TEMPLATE", ident.to_string().as_str(),)
+            .replace("MODEL", &get_model_init(&route, query, false,),),
+        route,
     )
 }
 /// Write the payload from the variant into the init.
@@ -124,11 +144,13 @@ mod test {
     use crate::{
         content::{
             module::{get_modules, templates::init::_INIT_COMMENT, test_constants::*, SeedModule},
+            visibility::Visibilities,
             SeedRoute,
         },
         find_routes,
     };
     use indexmap::map::IndexMap;
+    use std::path::Path;
     use syn::ItemEnum;
 
     fn get_routes(enum_string: &str,) -> ItemEnum {
@@ -142,8 +164,14 @@ mod test {
 
     #[test]
     fn write_init() {
-        let map: IndexMap<String, SeedModule,> =
-            get_modules(get_routes(_SIMPLE_ROUTE,), None, "", "",).0;
+        let map: IndexMap<String, SeedModule,> = get_modules(
+            get_routes(_SIMPLE_ROUTE,),
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
         let result: &SeedModule = map.get("login",).unwrap();
         let should_have = _SIMPLE_INIT;
         assert_eq!(result.init(), get_result(should_have));
@@ -155,6 +183,8 @@ mod test {
                 children: false,
                 id_param: false,
                 query: false,
+                as_path: None,
+                default_route: false,
                 content_to_load: "".to_string()
             }
         );
@@ -162,8 +192,14 @@ mod test {
 
     #[test]
     fn write_init_with_nested() {
-        let map: IndexMap<String, SeedModule,> =
-            get_modules(get_routes(_ROUTE_WITH_NESTED,), None, "", "",).0;
+        let map: IndexMap<String, SeedModule,> = get_modules(
+            get_routes(_ROUTE_WITH_NESTED,),
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
         let result: &SeedModule = map.get("login",).unwrap();
         let should_have = _INIT_WITH_NESTED;
         assert_eq!(result.init(), get_result(should_have));
@@ -175,6 +211,8 @@ mod test {
                 children: false,
                 id_param: false,
                 query: false,
+                as_path: None,
+                default_route: false,
                 content_to_load: "".to_string()
             }
         );
@@ -182,8 +220,14 @@ mod test {
 
     #[test]
     fn write_init_with_id_param() {
-        let map: IndexMap<String, SeedModule,> =
-            get_modules(get_routes(_ROUTE_WITH_ID_PARAM,), None, "", "",).0;
+        let map: IndexMap<String, SeedModule,> = get_modules(
+            get_routes(_ROUTE_WITH_ID_PARAM,),
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
         let result: &SeedModule = map.get("login",).unwrap();
         let should_have = _INIT_WITH_ID_PARAM;
         assert_eq!(result.init(), get_result(should_have));
@@ -195,14 +239,22 @@ mod test {
                 children: false,
                 id_param: true,
                 query: false,
+                as_path: None,
+                default_route: false,
                 content_to_load: "".to_string()
             }
         );
     }
     #[test]
     fn write_init_with_id_param_and_query() {
-        let map: IndexMap<String, SeedModule,> =
-            get_modules(get_routes(_ROUTE_WITH_ID_PARAM_AND_QUERY,), None, "", "",).0;
+        let map: IndexMap<String, SeedModule,> = get_modules(
+            get_routes(_ROUTE_WITH_ID_PARAM_AND_QUERY,),
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
         let result: &SeedModule = map.get("login",).unwrap();
         let should_have = _INIT_WITH_ID_PARAM_AND_QUERY;
         assert_eq!(result.init(), get_result(should_have));
@@ -214,6 +266,8 @@ mod test {
                 children: false,
                 id_param: true,
                 query: true,
+                as_path: None,
+                default_route: false,
                 content_to_load: "".to_string()
             }
         );
@@ -223,8 +277,9 @@ mod test {
         let map: IndexMap<String, SeedModule,> = get_modules(
             get_routes(_ROUTE_WITH_ID_PARAM_AND_QUERY_AND_CHILDREN,),
             None,
-            "",
-            "",
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
         )
         .0;
 
@@ -239,14 +294,22 @@ mod test {
                 children: true,
                 id_param: true,
                 query: true,
+                as_path: None,
+                default_route: false,
                 content_to_load: "".to_string()
             }
         );
     }
     #[test]
     fn write_init_with_id_param_and_children() {
-        let map: IndexMap<String, SeedModule,> =
-            get_modules(get_routes(_ROUTE_WITH_ID_PARAM_AND_CHILDREN,), None, "", "",).0;
+        let map: IndexMap<String, SeedModule,> = get_modules(
+            get_routes(_ROUTE_WITH_ID_PARAM_AND_CHILDREN,),
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
         let result: &SeedModule = map.get("login",).unwrap();
         let should_have = _INIT_WITH_ID_PARAM_AND_CHILDREN;
         assert_eq!(result.init(), get_result(should_have));
@@ -258,14 +321,22 @@ mod test {
                 children: true,
                 id_param: true,
                 query: false,
+                as_path: None,
+                default_route: false,
                 content_to_load: "".to_string()
             }
         );
     }
     #[test]
     fn write_init_with_query() {
-        let map: IndexMap<String, SeedModule,> =
-            get_modules(get_routes(_ROUTE_WITH_QUERY,), None, "", "",).0;
+        let map: IndexMap<String, SeedModule,> = get_modules(
+            get_routes(_ROUTE_WITH_QUERY,),
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
         let result: &SeedModule = map.get("login",).unwrap();
         let should_have = _INIT_WITH_QUERY;
         assert_eq!(result.init(), get_result(should_have));
@@ -277,14 +348,22 @@ mod test {
                 children: false,
                 id_param: false,
                 query: true,
+                as_path: None,
+                default_route: false,
                 content_to_load: "".to_string()
             }
         );
     }
     #[test]
     fn write_init_with_query_and_children() {
-        let map: IndexMap<String, SeedModule,> =
-            get_modules(get_routes(_ROUTE_WITH_QUERY_AND_CHILDREN,), None, "", "",).0;
+        let map: IndexMap<String, SeedModule,> = get_modules(
+            get_routes(_ROUTE_WITH_QUERY_AND_CHILDREN,),
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
         let result: &SeedModule = map.get("login",).unwrap();
         let should_have = _INIT_WITH_QUERY_AND_CHILDREN;
         assert_eq!(result.init(), get_result(should_have));
@@ -296,6 +375,8 @@ mod test {
                 children: true,
                 id_param: false,
                 query: true,
+                as_path: None,
+                default_route: false,
                 content_to_load: "".to_string()
             }
         );
@@ -303,8 +384,14 @@ mod test {
 
     #[test]
     fn write_init_with_children() {
-        let map: IndexMap<String, SeedModule,> =
-            get_modules(get_routes(_ROUTE_WITH_CHILDREN,), None, "", "",).0;
+        let map: IndexMap<String, SeedModule,> = get_modules(
+            get_routes(_ROUTE_WITH_CHILDREN,),
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
         let result: &SeedModule = map.get("login",).unwrap();
         let should_have = _INIT_WITH_CHILDREN;
         assert_eq!(result.init(), get_result(should_have));
@@ -316,8 +403,41 @@ mod test {
                 children: true,
                 id_param: false,
                 query: false,
+                as_path: None,
+                default_route: false,
                 content_to_load: "".to_string()
             }
         );
     }
+
+    #[test]
+    fn write_init_with_typed_query() {
+        let map: IndexMap<String, SeedModule,> = get_modules(
+            get_routes(_ROUTE_WITH_TYPED_QUERY,),
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
+        let result: &SeedModule = map.get("login",).unwrap();
+        assert_eq!(result.init(), get_result(_INIT_WITH_TYPED_QUERY));
+        assert!(result.model().contains(" pub query: LoginQuery,\n"));
+        assert!(result.model().contains(" pub struct LoginQuery {\n    pub name: String,\n"));
+        assert!(result.model().contains(" pub query_error: Option<String>,\n"));
+        assert_eq!(result.query().as_ref().unwrap().name, "LoginQuery");
+        assert!(syn::parse_file(result.model(),).is_ok());
+
+        let map: IndexMap<String, SeedModule,> = get_modules(
+            get_routes(_NESTED_ROUTE_WITH_TYPED_QUERY,),
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
+        let result: &SeedModule = map.get("login",).unwrap();
+        assert_eq!(result.init(), get_result(_INIT_WITH_PARSED_QUERY));
+        assert!(result.query().as_ref().unwrap().parsed_by_parent);
+    }
 }
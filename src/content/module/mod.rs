@@ -1,8 +1,16 @@
 //! Manage files considered as SeedModule, where a SeedModule is a rust file
 //! that will contain the TEA content.
 
-use crate::{content::SeedRoute, parser::view::get_view_attribute};
+use crate::{
+    content::{visibility::Visibilities, SeedRoute},
+    parser::{
+        module::has_derive,
+        route::{get_as_path_attribute, get_query_attribute, is_default_route},
+        view::get_view_attribute,
+    },
+};
 use indexmap::map::IndexMap;
+use std::path::{Path, PathBuf};
 
 #[rustfmt::skip]
 mod test_constants;
@@ -12,20 +20,30 @@ use crate::content::module::{
     init::{
         get_init_for_init_struct_variant, get_init_for_tuple_variant, get_init_for_unit_variant,
     },
+    model::get_route_model,
+    query::QueryStruct,
+    page_test::get_test_for_route,
     templates::{
         init::_INIT_COMMENT,
         message::{_MESSAGE_COMMENT, _MESSAGE_TEMPLATE},
-        model::{_MODEL_COMMENT, _MODEL_TEMPLATE},
+        model::_MODEL_COMMENT,
         route::{_ROUTES_COMMENT, _ROUTES_TEMPLATE},
-        update::{_UPDATE_COMMENT, _UPDATE_TEMPLATE},
+        test::_TEST_COMMENT,
+        update::{_UPDATE_COMMENT, _UPDATE_DISPATCH_TEMPLATE, _UPDATE_TEMPLATE},
         view::{_VIEW_COMMENT, _VIEW_TEMPLATE, _VIEW_TEMPLATE_WITH_ROUTES},
     },
 };
 use convert_case::{Case, Casing};
 use syn::{Fields, ItemEnum, Variant};
 
+pub mod crud;
+pub mod fetch;
 pub mod import;
 mod init;
+pub mod model;
+pub mod nested;
+pub mod query;
+mod page_test;
 
 /// Seed module that represent sometimes page or a global module
 /// TODO : in the future, should contain
@@ -40,6 +58,17 @@ pub struct SeedModule {
     model: String,
     msg: String,
     routes: String,
+    /// `#[cfg(test)] mod test` with a smoke test for init and view.
+    tests: String,
+    /// Generated init and view replaced by the ones dispatching on the nested
+    /// routes, the file gets the new ones while it still has these.
+    placeholders: Vec<String,>,
+    /// Typed query parameters from `#[query]`.
+    query: Option<QueryStruct,>,
+    /// Path of the struct of the pages from `#[crud]`.
+    crud: Option<String,>,
+    /// Type loaded by the init from `#[fetch]`.
+    fetch: Option<String,>,
     origin_route: Option<SeedRoute,>,
     meta: ModuleMeta,
 }
@@ -48,7 +77,7 @@ pub struct SeedModule {
 #[derive(Debug, Clone)]
 pub struct ModuleMeta {
     /// For example could be /pages/login.rs or /login.rs or mod.rs.
-    filepath: String,
+    filepath: PathBuf,
     /// The string that represent the import such as `mod login;`
     /// Ex: If module is login ->  'pub mod login;' if sub directory -> 'mod
     /// login;'
@@ -59,11 +88,11 @@ pub struct ModuleMeta {
     /// Ex: If parent directory, ->  'mod.rs' if  not ->  lib.rs
     /// if empty, it means the module is not reexported. This is expected
     /// behavior if we import directly in the target file.
-    import_file_location: String,
+    import_file_location: PathBuf,
 }
 
 impl ModuleMeta {
-    pub fn set_filepath(&mut self, filepath: String,) {
+    pub fn set_filepath(&mut self, filepath: PathBuf,) {
         self.filepath = filepath;
     }
 
@@ -71,7 +100,7 @@ impl ModuleMeta {
         self.mod_import = mod_import;
     }
 
-    pub fn set_import_file_location(&mut self, import_file_location: String,) {
+    pub fn set_import_file_location(&mut self, import_file_location: PathBuf,) {
         self.import_file_location = import_file_location;
     }
 
@@ -79,11 +108,11 @@ impl ModuleMeta {
         &self.mod_import
     }
 
-    pub fn import_file_location(&self,) -> &str {
+    pub fn import_file_location(&self,) -> &Path {
         &self.import_file_location
     }
 
-    pub fn filepath(&self,) -> &str {
+    pub fn filepath(&self,) -> &Path {
         &self.filepath
     }
 }
@@ -113,6 +142,26 @@ impl SeedModule {
         &self.routes
     }
 
+    pub fn tests(&self,) -> &str {
+        &self.tests
+    }
+
+    pub fn placeholders(&self,) -> &[String] {
+        &self.placeholders
+    }
+
+    pub fn query(&self,) -> &Option<QueryStruct,> {
+        &self.query
+    }
+
+    pub fn crud(&self,) -> &Option<String,> {
+        &self.crud
+    }
+
+    pub fn fetch(&self,) -> &Option<String,> {
+        &self.fetch
+    }
+
     pub fn origin_route(&self,) -> &Option<SeedRoute,> {
         &self.origin_route
     }
@@ -131,11 +180,16 @@ impl Default for SeedModule {
             model: "".to_string(),
             msg: "".to_string(),
             routes: "".to_string(),
+            tests: "".to_string(),
+            placeholders: Vec::new(),
+            query: None,
+            crud: None,
+            fetch: None,
             origin_route: None,
             meta: ModuleMeta {
-                filepath: "".to_string(),
+                filepath: PathBuf::new(),
                 mod_import: "".to_string(),
-                import_file_location: "".to_string(),
+                import_file_location: PathBuf::new(),
             },
         }
     }
@@ -175,6 +229,31 @@ impl SeedModule {
         self
     }
 
+    pub fn set_tests(&mut self, tests: String,) -> &mut SeedModule {
+        self.tests = tests;
+        self
+    }
+
+    pub fn set_placeholders(&mut self, placeholders: Vec<String,>,) -> &mut SeedModule {
+        self.placeholders = placeholders;
+        self
+    }
+
+    pub fn set_query(&mut self, query: Option<QueryStruct,>,) -> &mut SeedModule {
+        self.query = query;
+        self
+    }
+
+    pub fn set_crud(&mut self, crud: Option<String,>,) -> &mut SeedModule {
+        self.crud = crud;
+        self
+    }
+
+    pub fn set_fetch(&mut self, fetch: Option<String,>,) -> &mut SeedModule {
+        self.fetch = fetch;
+        self
+    }
+
     pub fn set_origin_route(&mut self, origin_route: Option<SeedRoute,>,) -> &mut SeedModule {
         self.origin_route = origin_route;
         self
@@ -191,15 +270,25 @@ impl SeedModule {
 pub fn get_modules(
     routes_enum: ItemEnum,
     modules_path: Option<String,>,
-    root_path_file: &str,
-    target_file_path: &str,
+    root_path_file: &Path,
+    target_file_path: &Path,
+    visibilities: &Visibilities,
 ) -> (IndexMap<String, SeedModule,>, ImportModule,) {
     let mut parent_module = if let Some(path,) = modules_path {
-        ImportModule::new_folder_module(path.as_str(), root_path_file, target_file_path,).ok()
+        ImportModule::new_folder_module(
+            path.as_str(),
+            root_path_file,
+            target_file_path,
+            visibilities.modules,
+        )
+        .ok()
     } else {
         ImportModule::new_target_file_module(root_path_file, target_file_path,).ok()
     };
 
+    // seed_routing calls the init of the modules of `RoutingModules` with the
+    // raw query
+    let parsed_by_parent = !has_derive(&routes_enum.attrs.iter(), "RoutingModules",);
     let mut map: IndexMap<String, SeedModule,> = IndexMap::new();
     for v in routes_enum.variants.iter() {
         let Variant {
@@ -209,7 +298,7 @@ pub fn get_modules(
             ..
         } = v;
 
-        if let Some(_,) = get_view_attribute(v.ident.clone(), v.attrs.iter(),) {
+        if get_view_attribute(v.ident.clone(), v.attrs.iter(),).is_some() {
         } else {
             let mut module = SeedModule::default();
 
@@ -221,10 +310,14 @@ pub fn get_modules(
                 name.as_str(),
                 root_path_file,
                 target_file_path,
+                visibilities,
             );
 
             parent_module = Some(updated_parent,);
             // prepare init content
+            let query = get_query_attribute(v.attrs.iter(),).map(|fields| {
+                QueryStruct::new(&ident.to_string(), fields, parsed_by_parent,)
+            },);
 
             let (init, route,) = match fields {
                 Fields::Unit => get_init_for_unit_variant(ident.clone(),),
@@ -232,9 +325,20 @@ pub fn get_modules(
                     get_init_for_tuple_variant(ident.clone(), fields.unnamed.iter(),)
                 },
                 Fields::Named(fields,) => {
-                    get_init_for_init_struct_variant(ident.clone(), fields.named.iter(),)
+                    get_init_for_init_struct_variant(
+                        ident.clone(),
+                        fields.named.iter(),
+                        query.as_ref(),
+                    )
                 },
             };
+            let route = SeedRoute {
+                as_path: get_as_path_attribute(v.attrs.iter(),),
+                default_route: is_default_route(v.attrs.iter(),),
+                ..route
+            };
+            // #[query] is only used by a variant with a query
+            let query = query.filter(|_| route.query,);
             // prepare view content
             let view = match fields {
                 Fields::Unit => _VIEW_TEMPLATE,
@@ -252,15 +356,31 @@ pub fn get_modules(
                 },
             };
 
+            let tea = visibilities.tea;
             module
                 .set_meta(module_meta,)
                 .set_origin_route(Some(route.clone(),),)
-                .set_init(format!("{} {}", _INIT_COMMENT, init),)
-                .set_model(format!("{} {}", _MODEL_COMMENT, _MODEL_TEMPLATE),)
-                .set_msg(format!("{} {}", _MESSAGE_COMMENT, _MESSAGE_TEMPLATE),)
-                .set_update(format!("{} {}", _UPDATE_COMMENT, _UPDATE_TEMPLATE),)
-                .set_view(format!("{} {}", _VIEW_COMMENT, view),)
-                .set_routes(format!("{} {}", _ROUTES_COMMENT, _ROUTES_TEMPLATE),);
+                .set_init(format!("{} {}", _INIT_COMMENT, tea.apply(&init)),)
+                .set_model(format!(
+                    "{} {}",
+                    _MODEL_COMMENT,
+                    tea.apply(&get_route_model(&route, query.as_ref(),))
+                ),)
+                .set_msg(format!("{} {}", _MESSAGE_COMMENT, tea.apply(_MESSAGE_TEMPLATE)),)
+                .set_update(format!("{} {}", _UPDATE_COMMENT, tea.apply(_UPDATE_TEMPLATE)),)
+                .set_view(format!(
+                    "{}// -> {}\n{}",
+                    _VIEW_COMMENT,
+                    route.url_path(),
+                    tea.apply(view)
+                ),)
+                .set_routes(format!("{} {}", _ROUTES_COMMENT, tea.apply(_ROUTES_TEMPLATE)),)
+                .set_tests(format!(
+                    "{}{}",
+                    _TEST_COMMENT,
+                    get_test_for_route(&route, query.as_ref(),)
+                ),)
+                .set_query(query,);
 
             map.insert(name, module,);
         }
@@ -268,37 +388,63 @@ pub fn get_modules(
     (map, parent_module.unwrap(),)
 }
 
+/// The arms of the root `update` dispatching the messages of every module, by
+/// Msg variant.
+pub fn get_dispatch_arms(
+    modules: &IndexMap<String, SeedModule,>,
+    modules_path: Option<&str,>,
+) -> IndexMap<String, String,> {
+    let mut map: IndexMap<String, String,> = IndexMap::new();
+    for (name, module,) in modules {
+        if let Some(route,) = module.origin_route() {
+            let module_path = match modules_path {
+                Some(path,) => format!("{}::{}", path, name),
+                None => name.clone(),
+            };
+            map.insert(
+                route.name.clone(),
+                _UPDATE_DISPATCH_TEMPLATE
+                    .replace("MODULE_PATH", &module_path,)
+                    .replace("ROUTE", &route.name,)
+                    .replace("NAME", name,),
+            );
+        }
+    }
+    map
+}
+
 /// Generate meta for file imports for file depending of tree folder.
 fn generate_module_meta(
     parent_module: ImportModule,
     name: &str,
-    root_path_file: &str,
-    target_file_path: &str,
+    root_path_file: &Path,
+    target_file_path: &Path,
+    visibilities: &Visibilities,
 ) -> (ModuleMeta, ImportModule,) {
     let mut parent = parent_module.clone();
     match parent_module.parent_type {
         ParentModuleType::TargetFile => {
-            let import = format!("mod {};", name);
+            let import = format!("{}mod {};", visibilities.modules.prefix(), name);
             parent.imports_content.push(import.clone(),);
             parent.imports_names.push(name.to_string(),);
             (
                 ModuleMeta {
-                    filepath: format!("{}/{}.rs", root_path_file, name),
+                    filepath: root_path_file.join(format!("{}.rs", name),),
                     mod_import: import,
-                    import_file_location: target_file_path.to_string(),
+                    import_file_location: target_file_path.to_path_buf(),
                 },
                 parent,
             )
         },
         ParentModuleType::Folder => {
-            let import = format!("pub mod {};", name);
+            let import = format!("{}mod {};", visibilities.folder_modules.prefix(), name);
             parent.imports_content.push(import.clone(),);
             parent.imports_names.push(name.to_string(),);
             (
                 ModuleMeta {
-                    filepath: format!("{}/{}.rs", parent.folder_path(), name),
+                    filepath: parent.folder_path().join(format!("{}.rs", name),),
                     mod_import: import,
-                    import_file_location: parent.meta().filepath.to_string(),
+                    import_file_location: parent.meta().filepath.clone(),
                 },
                 parent,
             )
@@ -307,37 +453,47 @@ fn generate_module_meta(
 }
 #[cfg(test)]
 mod test {
-    use crate::content::module::{
-        generate_module_meta,
-        import::{ImportModule, ParentModuleType},
+    use crate::content::{
+        module::{
+            generate_module_meta,
+            import::{ImportModule, ParentModuleType},
+        },
+        visibility::{Visibilities, Visibility},
     };
+    use std::path::Path;
 
     #[test]
     fn test_import_with_parent() {
-        let import_result =
-            ImportModule::new_folder_module("pages", "./my_app/src", "./my_app/src/lib.rs",);
+        let import_result = ImportModule::new_folder_module(
+            "pages",
+            Path::new("./my_app/src",),
+            Path::new("./my_app/src/lib.rs",),
+            Visibility::Private,
+        );
 
-        let mut import_module = import_result.unwrap();
+        let import_module = import_result.unwrap();
 
         let (module_meta, updated_parent,) = generate_module_meta(
             import_module,
             "login",
-            "./my_app/src/",
-            "./my_app/src/lib.rs",
+            Path::new("./my_app/src/",),
+            Path::new("./my_app/src/lib.rs",),
+            &Visibilities::default(),
         );
 
-        let (module_meta2, updated_parent_2,) = generate_module_meta(
+        let (_module_meta2, updated_parent_2,) = generate_module_meta(
             updated_parent.clone(),
             "register",
-            "./my_app/src/",
-            "./my_app/src/lib.rs",
+            Path::new("./my_app/src/",),
+            Path::new("./my_app/src/lib.rs",),
+            &Visibilities::default(),
         );
 
-        assert_eq!(module_meta.filepath, "./my_app/src/pages/login.rs");
+        assert_eq!(module_meta.filepath, Path::new("./my_app/src/pages/login.rs"));
         assert_eq!(module_meta.mod_import, "pub mod login;");
         assert_eq!(
             module_meta.import_file_location,
-            "./my_app/src/pages/mod.rs"
+            Path::new("./my_app/src/pages/mod.rs")
         );
 
         let imports = updated_parent.clone().imports_content;
@@ -355,22 +511,28 @@ mod test {
 
     #[test]
     fn test_import_with_target_file() {
-        let target_file_module =
-            ImportModule::new_target_file_module("./my_app/src", "./my_app/src/lib.rs",);
+        let target_file_module = ImportModule::new_target_file_module(
+            Path::new("./my_app/src",),
+            Path::new("./my_app/src/lib.rs",),
+        );
         let (module_meta, import,) = generate_module_meta(
             target_file_module.unwrap(),
             "login",
-            "./my_app/src",
-            "./my_app/src/lib.rs",
+            Path::new("./my_app/src",),
+            Path::new("./my_app/src/lib.rs",),
+            &Visibilities::default(),
         );
 
         let import_module = import;
 
         assert_eq!(import_module.parent_type, ParentModuleType::TargetFile);
-        assert_eq!(import_module.folder_path(), "./my_app/src");
-        assert_eq!(import_module.meta().filepath, "./my_app/src/lib.rs");
+        assert_eq!(import_module.folder_path(), Path::new("./my_app/src"));
+        assert_eq!(
+            import_module.meta().filepath,
+            Path::new("./my_app/src/lib.rs")
+        );
         assert_eq!(import_module.meta().mod_import, "");
-        assert_eq!(import_module.meta().import_file_location, "");
+        assert_eq!(import_module.meta().import_file_location, Path::new(""));
 
         let login = import_module
             .imports_content
@@ -380,8 +542,11 @@ mod test {
 
         eprintln!("{:?}", import_module.imports_content);
         assert_eq!(login.is_some(), true);
-        assert_eq!(module_meta.filepath, "./my_app/src/login.rs");
+        assert_eq!(module_meta.filepath, Path::new("./my_app/src/login.rs"));
         assert_eq!(module_meta.mod_import, "mod login;");
-        assert_eq!(module_meta.import_file_location, "./my_app/src/lib.rs");
+        assert_eq!(
+            module_meta.import_file_location,
+            Path::new("./my_app/src/lib.rs")
+        );
     }
 }
@@ -0,0 +1,132 @@
+//! The Model of the modules, it derives `Default` for the parent init and
+//! stores the payload of the route.
+
+use crate::content::{
+    module::{
+        query::QueryStruct,
+        templates::model::{_MODEL_FIELD_TEMPLATE, _MODEL_TEMPLATE},
+    },
+    SeedRoute,
+};
+
+/// The fields of the Model for the payload of the route, as name, type and
+/// the value built from the init arguments. The typed query is the default
+/// one when it does not match, with the error kept in `query_error`.
+pub fn get_payload_fields(
+    route: &SeedRoute,
+    query: Option<&QueryStruct,>,
+) -> Vec<(String, String, String,),> {
+    let mut fields = Vec::new();
+    if route.id_param {
+        fields.push(("id".to_string(), "String".to_string(), "id.to_string()".to_string(),),);
+    }
+    match query {
+        Some(query,) if route.query => {
+            fields.push((
+                "query".to_string(),
+                query.name.clone(),
+                format!("{}.unwrap_or_default()", query.parsed()),
+            ),);
+            fields.push((
+                "query_error".to_string(),
+                "Option<String>".to_string(),
+                format!("{}.err()", query.parsed()),
+            ),);
+        },
+        _ if route.query => fields.push((
+            "query".to_string(),
+            "IndexMap<String, String>".to_string(),
+            "query.clone()".to_string(),
+        ),),
+        _ => {},
+    }
+    fields
+}
+
+/// The Model with its fields as name and type, followed by the typed query
+/// if any.
+pub fn get_model(fields: &[(String, String,)], query: Option<&QueryStruct,>,) -> String {
+    let model = if fields.is_empty() {
+        _MODEL_TEMPLATE.replace("FIELDS", "",)
+    } else {
+        let fields: String = fields
+            .iter()
+            .map(|(name, ty,)| _MODEL_FIELD_TEMPLATE.replace("NAME", name,).replace("TYPE", ty,),)
+            .collect();
+        _MODEL_TEMPLATE.replace("FIELDS", &format!("{}\n ", fields),)
+    };
+    match query {
+        Some(query,) => format!("{}{}", model, query.content()),
+        None => model,
+    }
+}
+
+/// The Model returned by init with the payload of the route, `with_default`
+/// fills the other fields.
+pub fn get_model_init(
+    route: &SeedRoute,
+    query: Option<&QueryStruct,>,
+    with_default: bool,
+) -> String {
+    let fields = get_payload_fields(route, query,);
+    if fields.is_empty() {
+        return "Model::default()".to_string();
+    }
+    let mut init: String = fields
+        .iter()
+        .map(|(name, _, value,)| format!("           {}: {},\n", name, value),)
+        .collect();
+    if with_default {
+        init.push_str("           ..Default::default()\n",);
+    }
+    format!("Model {{\n{}       }}", init)
+}
+
+/// The Model of the route for the payload it receives.
+pub fn get_route_model(route: &SeedRoute, query: Option<&QueryStruct,>,) -> String {
+    let fields: Vec<(String, String,),> = get_payload_fields(route, query,)
+        .into_iter()
+        .map(|(name, ty, _,)| (name, ty,),)
+        .collect();
+    get_model(&fields, query,)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::content::{
+        module::model::{get_model_init, get_route_model},
+        SeedRoute,
+    };
+
+    #[test]
+    fn test_get_route_model() {
+        let mut route = SeedRoute {
+            name: "Login".to_string(),
+            nested: false,
+            children: true,
+            id_param: false,
+            query: false,
+            as_path: None,
+            default_route: false,
+            content_to_load: "".to_string(),
+        };
+        assert_eq!(
+            get_route_model(&route, None,),
+            "\n#[derive(Default)]\n VISIBILITY struct Model {}\n"
+        );
+        assert_eq!(get_model_init(&route, None, true,), "Model::default()");
+
+        route.id_param = true;
+        route.query = true;
+        assert_eq!(
+            get_route_model(&route, None,),
+            "\n#[derive(Default)]\n VISIBILITY struct Model {\n    VISIBILITY id: String,\n    \
+             VISIBILITY query: IndexMap<String, String>,\n }\n"
+        );
+        assert_eq!(
+            get_model_init(&route, None, true,),
+            "Model {\n           id: id.to_string(),\n           query: query.clone(),\n           \
+             ..Default::default()\n       }"
+        );
+    }
+}
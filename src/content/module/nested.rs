@@ -0,0 +1,351 @@
+//! Dispatch the view and the init of a module on its nested `Routes` once the
+//! module file declares them.
+
+use crate::{
+    content::{
+        module::{
+            model::{get_model, get_model_init, get_payload_fields},
+            page_test::get_nested_test,
+            query::QueryStruct,
+            templates::{
+                init::{
+                    _INIT_COMMENT, _NESTED_DISPATCH_INIT_TEMPLATE, _NESTED_INIT_ARM_TEMPLATE,
+                    _NESTED_LOCAL_INIT_ARM_TEMPLATE,
+                },
+                model::_MODEL_COMMENT,
+                test::_TEST_COMMENT,
+                view::{_NESTED_VIEW_ARM_TEMPLATE, _NESTED_VIEW_TEMPLATE, _VIEW_COMMENT},
+            },
+            SeedModule,
+        },
+        ContentSettings, SeedRoute,
+    },
+    parser::{
+        module::{has_derive, modules_path},
+        route::get_query_attribute,
+        view::get_view_attribute,
+    },
+};
+use convert_case::{Case, Casing};
+use syn::{Fields, ItemEnum, ItemStruct, Variant};
+
+/// Replace the view, the init and the test of a module with nested routes by
+/// the ones matching the variants of its `Routes`, the previous ones are kept
+/// as placeholders to replace them in the file when they are untouched.
+pub fn dispatch_nested_routes(
+    module: &mut SeedModule,
+    child_routes: &ItemEnum,
+    child_model: &ItemStruct,
+    settings: &ContentSettings,
+) {
+    let route = match module.origin_route() {
+        Some(route,) if (route.nested || route.children) && !child_routes.variants.is_empty() => {
+            route.clone()
+        },
+        _ => return,
+    };
+    let tea = settings.visibilities.tea;
+    let query = module.query().clone();
+    let init = get_nested_init(&route, query.as_ref(), child_routes,);
+    let view = get_nested_view(child_routes, child_model, settings,);
+    let mut fields: Vec<(String, String,),> = get_payload_fields(&route, query.as_ref(),)
+        .into_iter()
+        .map(|(name, ty, _,)| (name, ty,),)
+        .collect();
+    fields.extend(
+        get_child_modules(child_routes,)
+            .into_iter()
+            .map(|(name, path,)| (name, format!("{}::Model", path),),),
+    );
+    let placeholders = vec![
+        module.init().to_string(),
+        module.model().to_string(),
+        module.view().to_string(),
+        module.tests().to_string(),
+    ];
+    module
+        .set_init(format!("{} {}", _INIT_COMMENT, tea.apply(&init)),)
+        .set_model(format!(
+            "{} {}",
+            _MODEL_COMMENT,
+            tea.apply(&get_model(&fields, query.as_ref(),))
+        ),)
+        .set_view(format!(
+            "{}// -> {}\n{}",
+            _VIEW_COMMENT,
+            route.url_path(),
+            tea.apply(&view)
+        ),)
+        .set_tests(format!(
+            "{}{}",
+            _TEST_COMMENT,
+            get_nested_test(&route, query.as_ref(), child_routes,)
+        ),)
+        .set_placeholders(placeholders,);
+}
+
+/// The view matching every nested route, calling the view of the child
+/// module or the local view from `#[view]`.
+pub fn get_nested_view(
+    child_routes: &ItemEnum,
+    child_model: &ItemStruct,
+    settings: &ContentSettings,
+) -> String {
+    let modules_path = modules_path(&child_routes.attrs.iter(),);
+    let mut arms = String::new();
+    for v in child_routes.variants.iter() {
+        let (pattern, call,) = match get_view_attribute(v.ident.clone(), v.attrs.iter(),) {
+            Some((scope, view,),) => (
+                get_pattern(v, &[],),
+                settings.index.scoped_call(&view, child_model, &scope,),
+            ),
+            None => {
+                let name = v.ident.to_string().to_case(Case::Snake,);
+                let pattern = get_pattern(v, &["children"],);
+                let routes = match &v.fields {
+                    Fields::Unnamed(_,) => "nested, ",
+                    Fields::Named(_,) if pattern.contains("children",) => "children, ",
+                    _ => "",
+                };
+                let call = format!(
+                    "{}::view({}&model.{},).map_msg(Msg::{},)",
+                    get_module_path(modules_path.as_deref(), &name,),
+                    routes,
+                    name,
+                    v.ident
+                );
+                (pattern, call,)
+            },
+        };
+        arms.push_str(
+            &_NESTED_VIEW_ARM_TEMPLATE
+                .replace("PATTERN", &pattern,)
+                .replace("CALL", &call,),
+        );
+    }
+    _NESTED_VIEW_TEMPLATE.replace("ARMS", &arms,)
+}
+
+/// The init of the module with nested routes calling the init of the child
+/// modules, the variants with a local view have nothing to init. The typed
+/// query of a child is parsed here unless seed_routing calls its init.
+pub fn get_nested_init(
+    route: &SeedRoute,
+    query: Option<&QueryStruct,>,
+    child_routes: &ItemEnum,
+) -> String {
+    let modules_path = modules_path(&child_routes.attrs.iter(),);
+    let parsed_by_parent = !has_derive(&child_routes.attrs.iter(), "RoutingModules",);
+    let mut arms = String::new();
+    for v in child_routes.variants.iter() {
+        if get_view_attribute(v.ident.clone(), v.attrs.iter(),).is_some() {
+            arms.push_str(
+                &_NESTED_LOCAL_INIT_ARM_TEMPLATE.replace("PATTERN", &get_pattern(v, &[],),),
+            );
+            continue;
+        }
+        let name = v.ident.to_string().to_case(Case::Snake,);
+        let module_path = get_module_path(modules_path.as_deref(), &name,);
+        let child_query = get_query_attribute(v.attrs.iter(),)
+            .map(|fields| QueryStruct::new(&v.ident.to_string(), fields, parsed_by_parent,),)
+            .filter(|child_query| child_query.parsed_by_parent,);
+        let pattern = get_pattern(v, &["id", "query", "children",],);
+        let arguments = match &v.fields {
+            Fields::Unit => "".to_string(),
+            Fields::Unnamed(_,) => "nested, ".to_string(),
+            Fields::Named(fields,) => ["id", "query", "children",]
+                .iter()
+                .filter(|payload| {
+                    fields.named.iter().any(|f| f.ident.as_ref().unwrap() == *payload,)
+                },)
+                .map(|payload| match &child_query {
+                    Some(child_query,) if *payload == "query" => {
+                        format!("{}, ", child_query.parent_argument(&module_path,))
+                    },
+                    _ => format!("{}, ", payload),
+                },)
+                .collect(),
+        };
+        arms.push_str(
+            &_NESTED_INIT_ARM_TEMPLATE
+                .replace("PATTERN", &pattern,)
+                .replace("MODULE_PATH", &module_path,)
+                .replace("ARGUMENTS", &arguments,)
+                .replace("ROUTE", &v.ident.to_string(),)
+                .replace("NAME", &name,),
+        );
+    }
+    let (payload, nested,) = if route.nested {
+        ("nested: &Routes".to_string(), "nested",)
+    } else {
+        (get_payload(route, query,), "children",)
+    };
+    let with_default = !get_child_modules(child_routes,).is_empty();
+    _NESTED_DISPATCH_INIT_TEMPLATE
+        .replace("PAYLOAD", &payload,)
+        .replace("NESTED", nested,)
+        .replace("MODEL", &get_model_init(route, query, with_default,),)
+        .replace("ARMS", &arms,)
+        .replace("TEMPLATE", &route.name,)
+}
+
+/// The name and the path of the modules of the nested routes, the variants
+/// with a local view have none.
+fn get_child_modules(child_routes: &ItemEnum,) -> Vec<(String, String,),> {
+    let modules_path = modules_path(&child_routes.attrs.iter(),);
+    child_routes
+        .variants
+        .iter()
+        .filter(|v| get_view_attribute(v.ident.clone(), v.attrs.iter(),).is_none(),)
+        .map(|v| {
+            let name = v.ident.to_string().to_case(Case::Snake,);
+            let path = get_module_path(modules_path.as_deref(), &name,);
+            (name, path,)
+        },)
+        .collect()
+}
+
+/// The arguments of the init of a struct variant, in the order of
+/// `inject_variant_payload_in_function_call`.
+pub fn get_payload(route: &SeedRoute, query: Option<&QueryStruct,>,) -> String {
+    let mut payload = Vec::new();
+    if route.id_param {
+        payload.push("id: &str".to_string(),);
+    }
+    if route.query {
+        payload.push(query.map_or("query: &IndexMap<String, String>".to_string(), |query| {
+            query.argument()
+        },),);
+    }
+    if route.children {
+        payload.push("children: &Routes".to_string(),);
+    }
+    payload.join(", ",)
+}
+
+fn get_module_path(modules_path: Option<&str,>, name: &str,) -> String {
+    match modules_path {
+        Some(path,) => format!("{}::{}", path.trim_matches('/',).replace('/', "::",), name),
+        None => name.to_string(),
+    }
+}
+
+/// The pattern of the variant binding the wanted fields, `Routes::Admin(nested)`
+/// binds the nested routes of a tuple variant.
+fn get_pattern(v: &Variant, bindings: &[&str],) -> String {
+    match &v.fields {
+        Fields::Unit => format!("Routes::{}", v.ident),
+        Fields::Unnamed(_,) if bindings.is_empty() => format!("Routes::{}(..)", v.ident),
+        Fields::Unnamed(_,) => format!("Routes::{}(nested)", v.ident),
+        Fields::Named(fields,) => {
+            let mut names: Vec<String,> = fields
+                .named
+                .iter()
+                .map(|f| f.ident.as_ref().unwrap().to_string(),)
+                .filter(|name| bindings.contains(&name.as_str(),),)
+                .collect();
+            if names.len() < fields.named.len() {
+                names.push("..".to_string(),);
+            }
+            format!("Routes::{} {{ {} }}", v.ident, names.join(", ",))
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        content::{
+            module::{
+                nested::{get_nested_init, get_nested_view},
+                page_test::get_nested_test,
+                templates::test::_NESTED_MODULE_TEST_TEMPLATE,
+            },
+            ContentSettings, SeedRoute,
+        },
+        find_model, find_routes,
+    };
+
+    const CHILD_FILE: &str = r###"
+#[derive(Debug, PartialEq, Clone, ParseUrl)]
+#[modules_path = "sections"]
+pub enum Routes {
+    Users,
+    #[query = "from: String"]
+    Report { id: String, query: IndexMap<String, String> },
+    Settings(settings::Routes),
+    #[view = " => not_found"]
+    #[default_route]
+    NotFound,
+}
+
+pub struct Model {}
+"###;
+
+    const NESTED_VIEW: &str = r###"
+ VISIBILITY fn view(nested: &Routes, model: &Model) -> Node<Msg> {
+    match nested {
+        Routes::Users => sections::users::view(&model.users,).map_msg(Msg::Users,),
+        Routes::Report { .. } => sections::report::view(&model.report,).map_msg(Msg::Report,),
+        Routes::Settings(nested) => sections::settings::view(nested, &model.settings,).map_msg(Msg::Settings,),
+        Routes::NotFound => not_found(model,),
+    }
+ }
+"###;
+
+    const NESTED_INIT: &str = r###"
+ VISIBILITY fn init(url:Url, previous_state: &mut Model, id: &str, children: &Routes, orders: &mut impl Orders<Msg,> ) -> Model {
+       log!("init for Admin");
+       let mut model = Model {
+           id: id.to_string(),
+           ..Default::default()
+       };
+       match children {
+           Routes::Users => {
+               model.users = sections::users::init(url, &mut previous_state.users, &mut orders.proxy(Msg::Users,),);
+           },
+           Routes::Report { id, query } => {
+               model.report = sections::report::init(url, &mut previous_state.report, id, &sections::report::ReportQuery::from_query(query,), &mut orders.proxy(Msg::Report,),);
+           },
+           Routes::Settings(nested) => {
+               model.settings = sections::settings::init(url, &mut previous_state.settings, nested, &mut orders.proxy(Msg::Settings,),);
+           },
+           Routes::NotFound => {},
+       }
+       model
+       }
+"###;
+
+    #[test]
+    fn test_get_nested_view_and_init() {
+        let parsed_file = syn::parse_file(CHILD_FILE,).unwrap();
+        let routes = find_routes(&parsed_file,).unwrap();
+        let model = find_model(&parsed_file,).unwrap();
+
+        let view = get_nested_view(&routes, &model, &ContentSettings::default(),);
+        assert_eq!(view, NESTED_VIEW);
+
+        let route = SeedRoute {
+            name: "Admin".to_string(),
+            nested: false,
+            children: true,
+            id_param: true,
+            query: false,
+            as_path: None,
+            default_route: false,
+            content_to_load: "".to_string(),
+        };
+        assert_eq!(get_nested_init(&route, None, &routes,), NESTED_INIT);
+
+        let test = get_nested_test(&route, None, &routes,);
+        assert!(test.contains("let url = Url::new().set_path(&[\"admin\", \"1\", \"users\"]);"));
+        assert!(test.contains(
+            "let model = init(url, &mut Model::default(), \"1\", &Routes::Users, &mut orders,);"
+        ));
+        assert!(test.contains("let node = view(&Routes::Users, &model);"));
+
+        let routes = syn::parse_str::<syn::ItemEnum,>("enum Routes { Settings(settings::Routes) }",)
+            .unwrap();
+        assert_eq!(get_nested_test(&route, None, &routes,), _NESTED_MODULE_TEST_TEMPLATE);
+    }
+}
@@ -0,0 +1,185 @@
+//! Write the smoke test of a page calling its init with a fake Url and
+//! checking that its view renders.
+
+use crate::{
+    content::{
+        module::{
+            query::QueryStruct,
+            templates::test::{_MODULE_TEST_TEMPLATE, _NESTED_MODULE_TEST_TEMPLATE},
+        },
+        SeedRoute,
+    },
+    parser::route::get_as_path_attribute,
+};
+use convert_case::{Case, Casing};
+use syn::{Fields, ItemEnum, Variant};
+
+/// Get the test module for the page loaded by the route.
+/// Pages with nested routes only get an empty test module until their Routes
+/// enum has a variant to test with, see `get_nested_test`.
+pub fn get_test_for_route(route: &SeedRoute, query: Option<&QueryStruct,>,) -> String {
+    if route.nested || route.children {
+        return _NESTED_MODULE_TEST_TEMPLATE.to_string();
+    }
+    get_test(route, query, None,)
+}
+
+/// Get the test module for the page with nested routes, its init and view
+/// are called with the first variant of its Routes that can be built.
+pub fn get_nested_test(
+    route: &SeedRoute,
+    query: Option<&QueryStruct,>,
+    child_routes: &ItemEnum,
+) -> String {
+    match child_routes.variants.iter().find_map(get_variant_value,) {
+        Some(child,) => get_test(route, query, Some(&child,),),
+        None => _NESTED_MODULE_TEST_TEMPLATE.to_string(),
+    }
+}
+
+/// A nested route as its path segments and its value.
+struct ChildRoute {
+    path: Vec<String,>,
+    value: String,
+}
+
+/// The value of a unit variant or of a variant with `id` and `query`, the
+/// others need routes the page does not know.
+fn get_variant_value(v: &Variant,) -> Option<ChildRoute,> {
+    let segment = get_as_path_attribute(v.attrs.iter(),)
+        .map(|path| path.trim_matches('/',).to_string(),)
+        .unwrap_or_else(|| v.ident.to_string().to_case(Case::Snake,),);
+    let mut path: Vec<String,> = Vec::new();
+    if !segment.is_empty() {
+        path.push(format!("\"{}\"", segment),);
+    }
+    let value = match &v.fields {
+        Fields::Unit => format!("Routes::{}", v.ident),
+        Fields::Named(fields,) => {
+            let mut values: Vec<String,> = Vec::new();
+            for field in fields.named.iter() {
+                match field.ident.as_ref()?.to_string().as_str() {
+                    "id" => {
+                        path.push("\"1\"".to_string(),);
+                        values.push("id: \"1\".to_string()".to_string(),);
+                    },
+                    "query" => values.push("query: IndexMap::new()".to_string(),),
+                    _ => return None,
+                }
+            }
+            format!("Routes::{} {{ {} }}", v.ident, values.join(", "))
+        },
+        Fields::Unnamed(_,) => return None,
+    };
+    Some(ChildRoute {
+        path,
+        value,
+    },)
+}
+
+fn get_test(
+    route: &SeedRoute,
+    query: Option<&QueryStruct,>,
+    child: Option<&ChildRoute,>,
+) -> String {
+    let mut path: Vec<String,> = Vec::new();
+    let segment = route.path_segment();
+    if !segment.is_empty() {
+        path.push(format!("\"{}\"", segment),);
+    }
+    let mut payload = "".to_string();
+    if route.id_param {
+        path.push("\"1\"".to_string(),);
+        payload.push_str("\"1\", ",);
+    }
+    match query {
+        Some(query,) if route.query && query.parsed_by_parent => {
+            payload.push_str(&format!("&Ok({}::default(),), ", query.name),)
+        },
+        _ if route.query => payload.push_str("&IndexMap::new(), ",),
+        _ => {},
+    }
+    let view = match child {
+        Some(child,) => {
+            path.extend(child.path.iter().cloned(),);
+            payload.push_str(&format!("&{}, ", child.value),);
+            format!("view(&{}, &model)", child.value)
+        },
+        None => "view(&model)".to_string(),
+    };
+
+    let url = if path.is_empty() {
+        "Url::new()".to_string()
+    } else {
+        format!("Url::new().set_path(&[{}])", path.join(", "))
+    };
+
+    _MODULE_TEST_TEMPLATE
+        .replace("URL", url.as_str(),)
+        .replace("PAYLOAD", payload.as_str(),)
+        .replace("VIEW", view.as_str(),)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        content::{
+            module::{
+                get_modules,
+                templates::test::{_NESTED_MODULE_TEST_TEMPLATE, _TEST_COMMENT},
+                test_constants::*,
+            },
+            visibility::Visibilities,
+        },
+        find_routes,
+    };
+    use std::path::Path;
+
+    fn get_tests(enum_string: &str,) -> String {
+        let parsed_file = syn::parse_file(enum_string,).unwrap();
+        let routes = find_routes(&parsed_file,).expect("should have got the route",);
+        let map = get_modules(
+            routes,
+            None,
+            Path::new("",),
+            Path::new("",),
+            &Visibilities::default(),
+        )
+        .0;
+        map.get("login",).unwrap().tests().to_string()
+    }
+
+    #[test]
+    fn write_test_for_simple_route() {
+        let result = get_tests(_SIMPLE_ROUTE,);
+        assert!(result.starts_with(_TEST_COMMENT));
+        assert!(result.contains("let url = Url::new().set_path(&[\"login\"]);"));
+        assert!(result.contains("let model = init(url, &mut Model::default(), &mut orders,);"));
+        assert!(result.contains("let node = view(&model);"));
+        assert!(result.contains("assert!(node.is_el());"));
+    }
+
+    #[test]
+    fn write_test_for_id_param_and_query() {
+        let result = get_tests(_ROUTE_WITH_ID_PARAM_AND_QUERY,);
+        assert!(result.contains("let url = Url::new().set_path(&[\"login\", \"1\"]);"));
+        assert!(result.contains(
+            "let model = init(url, &mut Model::default(), \"1\", &IndexMap::new(), &mut orders,);"
+        ));
+    }
+
+    #[test]
+    fn write_test_for_parsed_query() {
+        let result = get_tests(_NESTED_ROUTE_WITH_TYPED_QUERY,);
+        assert!(result.contains(
+            "let model = init(url, &mut Model::default(), &Ok(LoginQuery::default(),), &mut \
+             orders,);"
+        ));
+    }
+
+    #[test]
+    fn write_test_for_nested() {
+        let result = get_tests(_ROUTE_WITH_NESTED,);
+        assert_eq!(result, format!("{}{}", _TEST_COMMENT, _NESTED_MODULE_TEST_TEMPLATE));
+    }
+}
@@ -0,0 +1,146 @@
+//! Typed query parameters from `#[query = "name: String, page: u32"]`, read
+//! from the query of the url by the parent dispatch or by the init of the
+//! module.
+
+use crate::content::module::templates::model::{
+    _MODEL_FIELD_TEMPLATE, _QUERY_INSERT_TEMPLATE, _QUERY_OPTIONAL_INSERT_TEMPLATE,
+    _QUERY_OPTIONAL_PARSE_TEMPLATE, _QUERY_PARSE_TEMPLATE, _QUERY_TEMPLATE,
+};
+
+/// The struct of the query parameters of a route, `LoginQuery` for `Login`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct QueryStruct {
+    pub name: String,
+    /// The parameters as name and type, an `Option` is not required in the
+    /// url.
+    pub fields: Vec<(String, String,),>,
+    /// The init is called by the dispatch proto_seeder writes, which parses
+    /// the query and gives the `Result` to the init. With `RoutingModules`
+    /// seed_routing gives the `IndexMap` and the init parses it.
+    pub parsed_by_parent: bool,
+}
+
+impl QueryStruct {
+    pub fn new(route_name: &str, fields: Vec<(String, String,),>, parsed_by_parent: bool,) -> Self {
+        QueryStruct {
+            name: format!("{}Query", route_name),
+            fields,
+            parsed_by_parent,
+        }
+    }
+
+    /// The query argument of the init.
+    pub fn argument(&self,) -> String {
+        if self.parsed_by_parent {
+            format!("query: &Result<{}, String>", self.name)
+        } else {
+            "query: &IndexMap<String, String>".to_string()
+        }
+    }
+
+    /// The parsed query as `Result`, read from the argument of the init.
+    pub fn parsed(&self,) -> String {
+        if self.parsed_by_parent {
+            "query.clone()".to_string()
+        } else {
+            format!("{}::from_query(query,)", self.name)
+        }
+    }
+
+    /// The query parsed by the parent for the init of the module at the path.
+    pub fn parent_argument(&self, module_path: &str,) -> String {
+        format!("&{}::{}::from_query(query,)", module_path, self.name)
+    }
+
+    /// The struct with `from_query` and `to_query`.
+    pub fn content(&self,) -> String {
+        let mut fields = String::new();
+        let mut parse = String::new();
+        let mut insert = String::new();
+        for (name, ty,) in self.fields.iter() {
+            fields.push_str(&_MODEL_FIELD_TEMPLATE.replace("NAME", name,).replace("TYPE", ty,),);
+            let (parse_template, insert_template,) = if ty.starts_with("Option<",) {
+                (_QUERY_OPTIONAL_PARSE_TEMPLATE, _QUERY_OPTIONAL_INSERT_TEMPLATE,)
+            } else {
+                (_QUERY_PARSE_TEMPLATE, _QUERY_INSERT_TEMPLATE,)
+            };
+            parse.push_str(&parse_template.replace("FIELD", name,),);
+            insert.push_str(&insert_template.replace("FIELD", name,),);
+        }
+        if !fields.is_empty() {
+            fields.push_str("\n ",);
+        }
+        _QUERY_TEMPLATE
+            .replace("NAME", &self.name,)
+            .replace("FIELDS", &fields,)
+            .replace("PARSE", &parse,)
+            .replace("INSERT", &insert,)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::content::module::query::QueryStruct;
+
+    const LOGIN_QUERY: &str = r###"
+#[derive(Debug, Default, Clone, PartialEq)]
+ VISIBILITY struct LoginQuery {
+    VISIBILITY name: String,
+    VISIBILITY page: Option<u32>,
+ }
+
+impl LoginQuery {
+    /// Read the parameters from the query of the url.
+    VISIBILITY fn from_query(query: &IndexMap<String, String,>,) -> Result<Self, String,> {
+        Ok(LoginQuery {
+            name: query
+                .get("name",)
+                .ok_or("missing query parameter name",)?
+                .parse()
+                .map_err(|_| "invalid query parameter name",)?,
+            page: query
+                .get("page",)
+                .map(|value| value.parse(),)
+                .transpose()
+                .map_err(|_| "invalid query parameter page",)?,
+        },)
+    }
+
+    /// The query of the url with these parameters.
+    VISIBILITY fn to_query(&self,) -> IndexMap<String, String,> {
+        let mut query = IndexMap::new();
+        query.insert("name".to_string(), self.name.to_string(),);
+        if let Some(value,) = &self.page {
+            query.insert("page".to_string(), value.to_string(),);
+        }
+        query
+    }
+}
+"###;
+
+    #[test]
+    fn test_query_struct_content() {
+        let query = QueryStruct::new(
+            "Login",
+            vec![
+                ("name".to_string(), "String".to_string(),),
+                ("page".to_string(), "Option<u32>".to_string(),),
+            ],
+            false,
+        );
+        assert_eq!(query.content(), LOGIN_QUERY);
+        assert_eq!(query.argument(), "query: &IndexMap<String, String>");
+        assert_eq!(query.parsed(), "LoginQuery::from_query(query,)");
+
+        let query = QueryStruct {
+            parsed_by_parent: true,
+            ..query
+        };
+        assert_eq!(query.argument(), "query: &Result<LoginQuery, String>");
+        assert_eq!(query.parsed(), "query.clone()");
+        assert_eq!(
+            query.parent_argument("pages::login"),
+            "&pages::login::LoginQuery::from_query(query,)"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+// ------ ------
+//     App
+// ------ ------
+
+pub const _APP_CARGO_TEMPLATE: &str = r###"[package]
+name = "NAME"
+version = "0.1.0"
+edition = "2018"
+
+[lib]
+crate-type = ["cdylib", "rlib"]
+
+[dependencies]
+seed = { git = "https://github.com/seed-rs/seed.git" }
+seed_routing = { git = "https://github.com/arn-the-long-beard/seed-routing.git", branch = "main" }
+serde = { version = "1.0.115", features = ["derive"] }
+serde_json = "1.0.51"
+
+[dependencies.web-sys]
+version = "0.3"
+
+[dev-dependencies]
+wasm-bindgen-test = "0.3.17"
+
+[profile.release]
+lto = true
+opt-level = 'z'
+codegen-units = 1
+
+[package.metadata.wasm-pack.profile.release]
+wasm-opt = ['-Os']
+"###;
+
+pub const _APP_INDEX_TEMPLATE: &str = r###"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <title>NAME</title>
+</head>
+<body>
+    <section id="app"></section>
+    <script type="module">
+        import init from '/pkg/package.js';
+        init('/pkg/package_bg.wasm');
+    </script>
+</body>
+</html>
+"###;
+
+pub const _APP_MAKEFILE_TEMPLATE: &str = r###"[env]
+PORT = "8000"
+
+[config]
+skip_core_tasks = true
+
+[tasks.default]
+alias = "build"
+
+[tasks.build]
+description = "Build with wasm-pack"
+install_crate = { crate_name = "wasm-pack", binary = "wasm-pack", test_arg = "-V" }
+command = "wasm-pack"
+args = ["build", "--target", "web", "--out-name", "package", "--dev"]
+
+[tasks.build_release]
+description = "Build with wasm-pack in release mode"
+install_crate = { crate_name = "wasm-pack", binary = "wasm-pack", test_arg = "-V" }
+command = "wasm-pack"
+args = ["build", "--target", "web", "--out-name", "package"]
+
+[tasks.serve]
+description = "Start server"
+install_crate = { crate_name = "microserver", binary = "microserver", test_arg = "-h" }
+command = "microserver"
+args = ["--port", "${PORT}"]
+
+[tasks.watch]
+description = "Watch files and recompile the project on change"
+run_task = [{ name = "build" }]
+watch = true
+
+[tasks.test_firefox]
+description = "Test in Firefox"
+install_crate = { crate_name = "wasm-pack", binary = "wasm-pack", test_arg = "-V" }
+command = "wasm-pack"
+args = ["test", "--firefox", "--headless"]
+"###;
+
+pub const _APP_LIB_TEMPLATE: &str = r###"use seed::{prelude::*, *};
+#[macro_use]
+extern crate seed_routing;
+use seed_routing::*;
+
+// ------ ------
+//     Init
+// ------ ------
+
+fn init(url: Url, orders: &mut impl Orders<Msg,>,) -> Model {
+    orders
+        .subscribe(Msg::UrlChanged,)
+        .subscribe(Msg::UrlRequested,);
+
+    let mut router: Router<Routes,> = Router::new();
+    router.init_url_and_navigation(url,);
+
+    Model {
+INITS        router,
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, RoutingModules)]
+#[modules_path = "pages"]
+pub enum Routes {
+VARIANTS}
+
+// ------ ------
+//     Model
+// ------ ------
+
+struct Model {
+FIELDS    router: Router<Routes,>,
+}
+
+// ------ ------
+//    Update
+// ------ ------
+
+pub enum Msg {
+    UrlChanged(subs::UrlChanged,),
+    UrlRequested(subs::UrlRequested,),
+MESSAGES}
+
+fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg,>,) {
+    match msg {
+        Msg::UrlChanged(subs::UrlChanged(url,),) => {
+            model.router.confirm_navigation(url,);
+            if let Some(current_route,) = model.router.current_route.clone() {
+                current_route.init(model, orders,);
+            }
+        },
+        Msg::UrlRequested(_,) => {},
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+
+fn view(model: &Model,) -> impl IntoNodes<Msg,> {
+    match &model.router.current_route {
+        Some(route,) => route.view(model,),
+        None => div!["Loading"],
+    }
+}
+
+// ------ ------
+//     Start
+// ------ ------
+
+#[wasm_bindgen(start)]
+pub fn start() {
+    App::start("app", init, update, view,);
+}
+"###;
+
+/// The first route is the root of the app and the default one.
+pub const _APP_DEFAULT_VARIANT_TEMPLATE: &str = r###"    #[default_route]
+    #[as_path = ""]
+"###;
+
+pub const _APP_VARIANT_TEMPLATE: &str = "    ROUTE,\n";
+
+pub const _APP_FIELD_TEMPLATE: &str = "    NAME: pages::NAME::Model,\n";
+
+pub const _APP_INIT_TEMPLATE: &str = "        NAME: Default::default(),\n";
+
+pub const _APP_MESSAGE_TEMPLATE: &str = "    ROUTE(pages::NAME::Msg,),\n";
@@ -0,0 +1,70 @@
+// ------ ------
+//   Component
+// ------ ------
+
+pub const _COMPONENT_TEMPLATE: &str = r###"use seed::{prelude::*, *};
+
+/// Build it with `NAME::new()` and the setters of its props, then use it as
+/// any node with `div![NAME::new().content("content")]`.
+pub struct NAME<Ms: 'static,> {
+FIELDS    attrs: Attrs,
+    content: Vec<Node<Ms,>,>,
+}
+
+impl<Ms: 'static,> NAME<Ms,> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+SETTERS
+    pub fn attrs(mut self, attrs: Attrs,) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn content(mut self, content: impl IntoNodes<Ms,>,) -> Self {
+        self.content = content.into_nodes();
+        self
+    }
+
+    fn view(self,) -> Node<Ms,> {
+        div![
+            C!["CLASS"CLASSES],
+            self.attrs,
+NODES            self.content,
+        ]
+    }
+}
+
+impl<Ms,> Default for NAME<Ms,> {
+    fn default() -> Self {
+        Self {
+DEFAULTS            attrs: Attrs::empty(),
+            content: Vec::new(),
+        }
+    }
+}
+
+impl<Ms,> UpdateEl<Ms,> for NAME<Ms,> {
+    fn update_el(self, el: &mut El<Ms,>,) {
+        self.view().update_el(el,)
+    }
+}
+"###;
+
+pub const _COMPONENT_FIELD_TEMPLATE: &str = "    PROP: TYPE,\n";
+
+pub const _COMPONENT_SETTER_TEMPLATE: &str = r###"
+    pub fn PROP(mut self, PROP: impl Into<TYPE,>,) -> Self {
+        self.PROP = PROP.into();
+        self
+    }
+"###;
+
+pub const _COMPONENT_DEFAULT_TEMPLATE: &str = "            PROP: Default::default(),\n";
+
+/// A `bool` prop toggles a class.
+pub const _COMPONENT_CLASS_TEMPLATE: &str = ", IF!(self.PROP => \"CLASS\")";
+
+/// The other props are shown as text.
+pub const _COMPONENT_NODE_TEMPLATE: &str =
+    "            div![C![\"CLASS\"], self.PROP.to_string()],\n";
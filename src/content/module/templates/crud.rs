@@ -0,0 +1,152 @@
+// ------ ------
+//     Crud
+// ------ ------
+
+pub const _CRUD_ROUTES_TEMPLATE: &str = r###"
+#[derive(Debug, PartialEq, Clone, RoutingModules)]
+ VISIBILITY enum Routes {
+    #[default_route]
+    #[as_path = ""]
+    #[view = " => list"]
+    List,
+    #[view = " => detail"]
+    Detail { id: String },
+    #[view = " => edit"]
+    Edit { id: String },
+ }
+"###;
+
+pub const _CRUD_INIT_TEMPLATE: &str = r###"
+ VISIBILITY fn init(url:Url, previous_state: &mut Model, PAYLOAD, orders: &mut impl Orders<Msg,> ) -> Model {
+       log!("init for TEMPLATE");
+       orders.send_msg(Msg::Fetch,);
+       let mut model = MODEL;
+       model.selected = match NESTED {
+           Routes::Detail { id } | Routes::Edit { id } => Some(id.clone(),),
+           Routes::List => None,
+       };
+       model
+       }
+"###;
+
+pub const _CRUD_USE_TEMPLATE: &str = "\nuse ITEM_PATH;\n";
+
+pub const _CRUD_MESSAGE_TEMPLATE: &str = r###"
+ VISIBILITY enum Msg {
+    Fetch,
+    Fetched(Result<Vec<ITEM>, String>),
+    Save,
+    Saved(Result<ITEM, String>),
+CHANGED }
+"###;
+
+pub const _CRUD_CHANGED_MESSAGE_TEMPLATE: &str = "    VARIANT(String),\n";
+
+pub const _CRUD_UPDATE_TEMPLATE: &str = r###"
+ VISIBILITY fn update(msg: Msg, model: &mut Model, _orders: &mut impl Orders<Msg,>,) {
+    match msg {
+        Msg::Fetch => {
+            // fetch the items then send Msg::Fetched
+        },
+        Msg::Fetched(Ok(items,),) => {
+            model.form = model
+                .selected
+                .as_deref()
+                .and_then(|id| find_item(&items, id,),)
+                .cloned()
+                .unwrap_or_default();
+            model.items = items;
+        },
+        Msg::Save => {
+            // send model.form then send Msg::Saved
+        },
+        Msg::Saved(Ok(item,),) => {
+            let index = INDEX;
+            match index.and_then(|index| model.items.get_mut(index,),) {
+                Some(existing,) => *existing = item,
+                None => model.items.push(item,),
+            }
+        },
+        Msg::Fetched(Err(error,),) | Msg::Saved(Err(error,),) => model.error = Some(error,),
+CHANGED    }
+}
+
+fn find_item<'a>(items: &'a [ITEM], id: &str,) -> Option<&'a ITEM,> {
+    FIND
+}
+"###;
+
+pub const _CRUD_CHANGED_TEMPLATE: &str =
+    "        Msg::VARIANT(value,) => model.form.FIELD = value,\n";
+
+pub const _CRUD_PARSED_CHANGED_TEMPLATE: &str = r###"        Msg::VARIANT(value,) => {
+            if let Ok(value,) = value.parse() {
+                model.form.FIELD = value;
+            }
+        },
+"###;
+
+pub const _CRUD_INDEX_BY_ID: &str =
+    "model.items.iter().position(|existing| existing.id == item.id,)";
+
+pub const _CRUD_INDEX_BY_POSITION: &str =
+    "model.selected.as_deref().and_then(|id| id.parse::<usize>().ok(),)";
+
+pub const _CRUD_FIND_BY_ID: &str = "items.iter().find(|item| item.id.to_string() == id,)";
+
+pub const _CRUD_FIND_BY_POSITION: &str =
+    "id.parse::<usize>().ok().and_then(|index| items.get(index,),)";
+
+pub const _CRUD_VIEWS_TEMPLATE: &str = r###"
+// -> /
+VISIBILITY fn list(model: &Model,) -> Node<Msg,> {
+    table![
+        tr![HEADERS
+            th![""],
+        ],
+        ITEMS {
+            tr![
+CELLS                td![a![attrs! {At::Href => DETAIL_URL}, "Show"]],
+            ]
+        },),
+    ]
+}
+
+// -> /detail/:id
+VISIBILITY fn detail(model: &Model,) -> Node<Msg,> {
+    let id = model.selected.clone().unwrap_or_default();
+    match find_item(&model.items, &id,) {
+        Some(item,) => div![
+ROWS            a![attrs! {At::Href => EDIT_URL}, "Edit"],
+        ],
+        None => div!["Not found"],
+    }
+}
+
+// -> /edit/:id
+VISIBILITY fn edit(model: &Model,) -> Node<Msg,> {
+    form![
+        ev(Ev::Submit, |event| {
+            event.prevent_default();
+            Msg::Save
+        },),
+INPUTS        button!["Save"],
+        model.error.as_ref().map(|error| p![error],),
+    ]
+}
+"###;
+
+pub const _CRUD_HEADER_TEMPLATE: &str = "\n            th![\"LABEL\"],";
+
+pub const _CRUD_CELL_TEMPLATE: &str = "                td![item.FIELD.to_string()],\n";
+
+pub const _CRUD_ROW_TEMPLATE: &str = "            p![format!(\"LABEL : {}\", item.FIELD)],\n";
+
+pub const _CRUD_INPUT_TEMPLATE: &str = r###"        label![
+            "LABEL",
+            input![
+                attrs! {At::Value => model.form.FIELD.to_string()},
+                input_ev(Ev::Input, Msg::VARIANT,),
+            ],
+        ],
+"###;
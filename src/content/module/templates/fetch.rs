@@ -0,0 +1,75 @@
+// ------ ------
+//     Fetch
+// ------ ------
+
+pub const _FETCH_INIT_TEMPLATE: &str = r###"
+ VISIBILITY fn init(url:Url, previous_state: &mut Model, PAYLOAD, orders: &mut impl Orders<Msg,> ) -> Model {
+       log!("init for TEMPLATE");
+       let model = MODEL;
+       let id = model.id.clone();
+       orders.perform_cmd(async move { Msg::Fetched(request(id,).await,) },);
+       model
+       }
+
+/// Request the ITEM of the page, adapt the url to your api.
+async fn request(id: String,) -> fetch::Result<ITEM,> {
+    Request::new(format!("/api/URL/{}", id),)
+        .fetch()
+        .await?
+        .check_status()?
+        .json()
+        .await
+}
+"###;
+
+pub const _FETCH_USE_TEMPLATE: &str = "\nuse ITEM_PATH;\n";
+
+pub const _FETCH_STATE_USE_TEMPLATE: &str = "\nuse crate::State;\n";
+
+/// Loading state of the data of the pages, written once on the target file.
+pub const _FETCH_STATE_TEMPLATE: &str = r###"
+/// Loading state of the data of the `#[fetch]` pages.
+#[derive(Debug)]
+ VISIBILITY enum State<T,> {
+    Loading,
+    Loaded(T,),
+    Failed(String,),
+}
+
+impl<T,> Default for State<T,> {
+    fn default() -> Self {
+        State::Loading
+    }
+}
+"###;
+
+pub const _FETCH_MESSAGE_TEMPLATE: &str = r###"
+ VISIBILITY enum Msg {
+    Fetched(fetch::Result<ITEM,>,),
+ }
+"###;
+
+pub const _FETCH_UPDATE_TEMPLATE: &str = r###"
+ VISIBILITY fn update(msg: Msg, model: &mut Model, _: &mut impl Orders<Msg,>,) {
+    match msg {
+        Msg::Fetched(Ok(NAME,),) => model.NAME = State::Loaded(NAME,),
+        Msg::Fetched(Err(error,),) => model.NAME = State::Failed(format!("{:?}", error),),
+    }
+}
+"###;
+
+pub const _FETCH_VIEW_TEMPLATE: &str = r###"
+ VISIBILITY fn view(model: &Model) -> Node<Msg> {
+    match &model.NAME {
+        State::Loading => div!["Loading"],
+        State::Loaded(NAME,) => div![
+ROWS        ],
+        State::Failed(error,) => div![error],
+    }
+ }
+"###;
+
+pub const _FETCH_ROW_TEMPLATE: &str = "            p![format!(\"LABEL : {}\", NAME.FIELD)],\n";
+
+/// Shown when the fields of the struct are unknown.
+pub const _FETCH_DEBUG_ROW_TEMPLATE: &str = "            format!(\"{:?}\", NAME),\n";
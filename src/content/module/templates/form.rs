@@ -0,0 +1,54 @@
+// ------ ------
+//     Form
+// ------ ------
+
+pub const _FORM_USE_TEMPLATE: &str = "use ITEM_PATH;\n";
+
+pub const _FORM_MODEL_TEMPLATE: &str = r###"
+#[derive(Default)]
+ VISIBILITY struct Model {
+FIELD }
+"###;
+
+pub const _FORM_MODEL_FIELD_TEMPLATE: &str = "    VISIBILITY NAME: ITEM,\n";
+
+pub const _FORM_MESSAGE_TEMPLATE: &str = r###"
+ VISIBILITY enum Msg {
+VARIANTS }
+"###;
+
+pub const _FORM_VARIANT_TEMPLATE: &str = "    VARIANT(String),\n";
+
+pub const _FORM_UPDATE_TEMPLATE: &str = r###"
+ VISIBILITY fn update(msg: Msg, model: &mut Model, _: &mut impl Orders<Msg,>,) {
+    match msg {
+ARMS    }
+}
+"###;
+
+pub const _FORM_ARM_TEMPLATE: &str = "        Msg::VARIANT(value,) => SET,\n";
+
+pub const _FORM_PARSED_ARM_TEMPLATE: &str = r###"        Msg::VARIANT(value,) => {
+            if let Ok(value,) = value.parse() {
+                SET;
+            }
+        },
+"###;
+
+pub const _FORM_VIEW_TEMPLATE: &str = r###"
+/// Form editing the ITEM of the Model.
+VISIBILITY fn NAME(model: &Model,) -> Node<Msg,> {
+    form![
+        ev(Ev::Submit, |event| event.prevent_default(),),
+INPUTS    ]
+}
+"###;
+
+pub const _FORM_INPUT_TEMPLATE: &str = r###"        label![
+            "LABEL",
+            input![
+                attrs! {At::Value => VALUE.to_string()},
+                input_ev(Ev::Input, Msg::VARIANT,),
+            ],
+        ],
+"###;
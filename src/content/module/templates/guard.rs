@@ -3,12 +3,16 @@
 // ------ ------
 
 pub const _GUARD_TEMPLATE: &str = r###"
-pub fn GUARD_NAME(PAYLOAD) -> Option<bool>
+VISIBILITY fn GUARD_NAME(PAYLOAD) -> RETURN_TYPE
 {
-if 1+1 ==2 {
- log!("Need to implement nice conditions");
-}
-
-None
+BODY
 }
 "###;
+
+/// Body when the condition can be guessed from the type of the scope.
+pub const _GUARD_CONDITION_BODY: &str = r###"let allowed = CONDITION;
+RETURN"###;
+
+/// Body when nothing is known about the scope.
+pub const _GUARD_DEFAULT_BODY: &str = r###" log!("Need to implement nice conditions");
+RETURN"###;
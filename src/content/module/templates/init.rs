@@ -10,22 +10,39 @@ pub const _INIT_COMMENT: &str = r###"
 "###;
 
 pub const _SIMPLE_INIT_TEMPLATE: &str = r###"
- pub fn init(url:Url, previous_state: &mut Model, orders: &mut impl Orders<Msg,> ) -> Model {
+ VISIBILITY fn init(url:Url, previous_state: &mut Model, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for TEMPLATE");
-       Model {}
+       MODEL
        }
 "###;
 
 pub const _NESTED_INIT_TEMPLATE: &str = r###"
- pub fn init(url:Url, previous_state: &mut Model, nested: &Routes, orders: &mut impl Orders<Msg,> ) -> Model {
+ VISIBILITY fn init(url:Url, previous_state: &mut Model, nested: &Routes, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for TEMPLATE");
-       Model {}
+       MODEL
        }
 "###;
 
 pub const _PAYLOAD_INIT_TEMPLATE: &str = r###"
- pub fn init(url:Url, previous_state: &mut Model, PAYLOAD, orders: &mut impl Orders<Msg,> ) -> Model {
+ VISIBILITY fn init(url:Url, previous_state: &mut Model, PAYLOAD, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for TEMPLATE");
-       Model {}
+       MODEL
        }
 "###;
+
+pub const _NESTED_DISPATCH_INIT_TEMPLATE: &str = r###"
+ VISIBILITY fn init(url:Url, previous_state: &mut Model, PAYLOAD, orders: &mut impl Orders<Msg,> ) -> Model {
+       log!("init for TEMPLATE");
+       let mut model = MODEL;
+       match NESTED {
+ARMS       }
+       model
+       }
+"###;
+
+pub const _NESTED_INIT_ARM_TEMPLATE: &str = r###"           PATTERN => {
+               model.NAME = MODULE_PATH::init(url, &mut previous_state.NAME, ARGUMENTS&mut orders.proxy(Msg::ROUTE,),);
+           },
+"###;
+
+pub const _NESTED_LOCAL_INIT_ARM_TEMPLATE: &str = "           PATTERN => {},\n";
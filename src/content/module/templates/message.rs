@@ -10,5 +10,5 @@ pub const _MESSAGE_COMMENT: &str = r###"
 "###;
 
 pub const _MESSAGE_TEMPLATE: &str = r###"
- pub enum Msg {}
+ VISIBILITY enum Msg {}
 "###;
@@ -1,7 +1,14 @@
+pub mod app;
+pub mod component;
+pub mod crud;
+pub mod fetch;
+pub mod form;
 pub mod guard;
 pub mod init;
 pub mod message;
 pub mod model;
+pub mod nav;
 pub mod route;
+pub mod test;
 pub mod update;
 pub mod view;
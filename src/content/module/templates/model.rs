@@ -10,5 +10,49 @@ pub const _MODEL_COMMENT: &str = r###"
 "###;
 
 pub const _MODEL_TEMPLATE: &str = r###"
- pub struct Model {}
+#[derive(Default)]
+ VISIBILITY struct Model {FIELDS}
+"###;
+
+pub const _MODEL_FIELD_TEMPLATE: &str = "\n    VISIBILITY NAME: TYPE,";
+
+pub const _QUERY_TEMPLATE: &str = r###"
+#[derive(Debug, Default, Clone, PartialEq)]
+ VISIBILITY struct NAME {FIELDS}
+
+impl NAME {
+    /// Read the parameters from the query of the url.
+    VISIBILITY fn from_query(query: &IndexMap<String, String,>,) -> Result<Self, String,> {
+        Ok(NAME {
+PARSE        },)
+    }
+
+    /// The query of the url with these parameters.
+    VISIBILITY fn to_query(&self,) -> IndexMap<String, String,> {
+        let mut query = IndexMap::new();
+INSERT        query
+    }
+}
+"###;
+
+pub const _QUERY_PARSE_TEMPLATE: &str = r###"            FIELD: query
+                .get("FIELD",)
+                .ok_or("missing query parameter FIELD",)?
+                .parse()
+                .map_err(|_| "invalid query parameter FIELD",)?,
+"###;
+
+pub const _QUERY_OPTIONAL_PARSE_TEMPLATE: &str = r###"            FIELD: query
+                .get("FIELD",)
+                .map(|value| value.parse(),)
+                .transpose()
+                .map_err(|_| "invalid query parameter FIELD",)?,
+"###;
+
+pub const _QUERY_INSERT_TEMPLATE: &str =
+    "        query.insert(\"FIELD\".to_string(), self.FIELD.to_string(),);\n";
+
+pub const _QUERY_OPTIONAL_INSERT_TEMPLATE: &str = r###"        if let Some(value,) = &self.FIELD {
+            query.insert("FIELD".to_string(), value.to_string(),);
+        }
 "###;
@@ -0,0 +1,17 @@
+/// The navigation menu on the target file.
+pub const _NAV_TEMPLATE: &str = r###"/// Link to every route without payload, regenerated by proto_seeder when the
+/// Routes change.
+VISIBILITY fn nav(MODEL: &Model,) -> Node<Msg,> {
+    nav![ul![
+LINKS    ]]
+}
+"###;
+
+pub const _NAV_LINK_TEMPLATE: &str = r###"        // -> URL
+        li![a![attrs! {At::Href => Routes::ROUTE.to_url()}, "LABEL"]],
+"###;
+
+/// Link hidden when the guard of the route does not allow it.
+pub const _NAV_GUARDED_LINK_TEMPLATE: &str = r###"        // -> URL
+        IF!(CONDITION => li![a![attrs! {At::Href => Routes::ROUTE.to_url()}, "LABEL"]]),
+"###;
@@ -11,7 +11,7 @@ pub const _ROUTES_COMMENT: &str = r###"
 
 pub const _ROUTES_TEMPLATE: &str = r###"
 #[derive(Debug, PartialEq, Clone, RoutingModules)]
- pub enum Routes {
+ VISIBILITY enum Routes {
 
  }
 "###;
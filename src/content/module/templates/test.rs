@@ -0,0 +1,69 @@
+// ------ ------
+//     Tests
+// ------ ------
+
+pub const _ROUTES_TEST_COMMENT: &str = r###"
+// ------ ------
+//     Routes tests
+// ------ ------
+"###;
+
+pub const _ROUTES_TEST_TEMPLATE: &str = r###"
+#[cfg(test)]
+mod routes_test {
+    use super::*;
+TESTS}
+"###;
+
+pub const _ROUTE_TEST_TEMPLATE: &str = r###"
+    #[test]
+    fn test_NAME_route() {
+QUERY        let route = ROUTE;
+        assert_eq!(Routes::parse_path("URL").unwrap(), route);
+        assert_eq!(route.as_path(), "URL");
+    }
+"###;
+
+pub const _QUERY_TEST_TEMPLATE: &str = r###"        let mut query: IndexMap<String, String> = IndexMap::new();
+        query.insert("name".to_string(), "JohnDoe".to_string());
+"###;
+
+pub const _TEST_COMMENT: &str = r###"
+// ------ ------
+//     Tests
+// ------ ------
+"###;
+
+pub const _MODULE_TEST_TEMPLATE: &str = r###"
+#[cfg(test)]
+mod test {
+    use super::*;
+    use seed::app::OrdersContainer;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn test_init_and_view() {
+        let app = App::start(seed::body(), |_, _| Model::default(), update, |_| empty![]);
+        let mut orders = OrdersContainer::new(app,);
+        let url = URL;
+        let model = init(url, &mut Model::default(), PAYLOAD&mut orders,);
+        let node = VIEW;
+        assert!(node.is_el());
+    }
+}
+"###;
+
+/// For a page with nested routes until its Routes has a variant to test with.
+pub const _NESTED_MODULE_TEST_TEMPLATE: &str = r###"
+#[cfg(test)]
+mod test {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    // Add variants to Routes and a test calling init and view with one of them.
+}
+"###;
@@ -10,8 +10,17 @@ pub const _UPDATE_COMMENT: &str = r###"
 "###;
 
 pub const _UPDATE_TEMPLATE: &str = r###"
-pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg,>,)
+VISIBILITY fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg,>,)
 {
 
 }
 "###;
+
+/// Arm of the root `update` sending the message of a module to its own
+/// `update`.
+pub const _UPDATE_DISPATCH_TEMPLATE: &str = r###"        Msg::ROUTE(NAME_msg,) => MODULE_PATH::update(
+            NAME_msg,
+            &mut model.NAME,
+            &mut orders.proxy(Msg::ROUTE,),
+        ),
+"###;
@@ -10,13 +10,22 @@ pub const _VIEW_COMMENT: &str = r###"
 "###;
 
 pub const _VIEW_TEMPLATE: &str = r###"
- pub fn view(model: &Model) -> Node<Msg> {
+ VISIBILITY fn view(model: &Model) -> Node<Msg> {
  div![""]
  }
 "###;
 
 pub const _VIEW_TEMPLATE_WITH_ROUTES: &str = r###"
- pub fn view(nested: &Routes, model: &Model) -> Node<Msg> {
+ VISIBILITY fn view(nested: &Routes, model: &Model) -> Node<Msg> {
  div![""]
  }
 "###;
+
+pub const _NESTED_VIEW_TEMPLATE: &str = r###"
+ VISIBILITY fn view(nested: &Routes, model: &Model) -> Node<Msg> {
+    match nested {
+ARMS    }
+ }
+"###;
+
+pub const _NESTED_VIEW_ARM_TEMPLATE: &str = "        PATTERN => CALL,\n";
@@ -58,6 +58,22 @@ pub enum Routes {
     }
 "###;
 
+pub const _ROUTE_WITH_TYPED_QUERY: &str = r###"
+#[derive(Debug, PartialEq, Clone, RoutingModules)]
+pub enum Routes {
+    #[query = "name: String, page: Option<u32>"]
+    Login{  query : IndexMap<String, String> }
+    }
+"###;
+
+pub const _NESTED_ROUTE_WITH_TYPED_QUERY: &str = r###"
+#[derive(Debug, PartialEq, Clone, ParseUrl)]
+pub enum Routes {
+    #[query = "name: String, page: Option<u32>"]
+    Login{  query : IndexMap<String, String> }
+    }
+"###;
+
 pub const _ROUTE_WITH_CHILDREN: &str = r###"
 #[derive(Debug, PartialEq, Clone, RoutingModules)]
 pub enum Routes {
@@ -70,68 +86,96 @@ pub enum Routes {
 pub const _SIMPLE_INIT: &str = r###"
  pub fn init(url:Url, previous_state: &mut Model, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for Login");
-       Model {}
+       Model::default()
        }
 "###;
 
 pub const _INIT_WITH_NESTED: &str = r###"
  pub fn init(url:Url, previous_state: &mut Model, nested: &Routes, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for Login");
-       Model {}
+       Model::default()
        }
 "###;
 
 pub const _INIT_WITH_ID_PARAM: &str = r###"
  pub fn init(url:Url, previous_state: &mut Model, id: &str, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for Login");
-       Model {}
+       Model {
+           id: id.to_string(),
+       }
        }
 "###;
 
 pub const _INIT_WITH_ID_PARAM_AND_QUERY: &str = r###"
  pub fn init(url:Url, previous_state: &mut Model, id: &str, query: &IndexMap<String, String>, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for Login");
-       Model {}
+       Model {
+           id: id.to_string(),
+           query: query.clone(),
+       }
        }
 "###;
 
 pub const _INIT_WITH_ID_PARAM_AND_QUERY_AND_CHILDREN: &str = r###"
  pub fn init(url:Url, previous_state: &mut Model, id: &str, query: &IndexMap<String, String>, children: &Routes, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for Login");
-       Model {}
+       Model {
+           id: id.to_string(),
+           query: query.clone(),
+       }
        }
 "###;
 
 pub const _INIT_WITH_ID_PARAM_AND_CHILDREN: &str = r###"
  pub fn init(url:Url, previous_state: &mut Model, id: &str, children: &Routes, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for Login");
-       Model {}
+       Model {
+           id: id.to_string(),
+       }
        }
 "###;
 
 pub const _INIT_WITH_QUERY: &str = r###"
  pub fn init(url:Url, previous_state: &mut Model, query: &IndexMap<String, String>, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for Login");
-       Model {}
+       Model {
+           query: query.clone(),
+       }
        }
 "###;
 
 pub const _INIT_WITH_QUERY_AND_CHILDREN: &str = r###"
  pub fn init(url:Url, previous_state: &mut Model, query: &IndexMap<String, String>, children: &Routes, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for Login");
-       Model {}
+       Model {
+           query: query.clone(),
+       }
        }
 "###;
 
 pub const _INIT_WITH_CHILDREN: &str = r###"
  pub fn init(url:Url, previous_state: &mut Model, children: &Routes, orders: &mut impl Orders<Msg,> ) -> Model {
        log!("init for Login");
-       Model {}
+       Model::default()
        }
 "###;
 
+pub const _INIT_WITH_TYPED_QUERY: &str = r###"
+ pub fn init(url:Url, previous_state: &mut Model, query: &IndexMap<String, String>, orders: &mut impl Orders<Msg,> ) -> Model {
+       log!("init for Login");
+       Model {
+           query: LoginQuery::from_query(query,).unwrap_or_default(),
+           query_error: LoginQuery::from_query(query,).err(),
+       }
+       }
+"###;
 
-
-
-
-
+pub const _INIT_WITH_PARSED_QUERY: &str = r###"
+ pub fn init(url:Url, previous_state: &mut Model, query: &Result<LoginQuery, String>, orders: &mut impl Orders<Msg,> ) -> Model {
+       log!("init for Login");
+       Model {
+           query: query.clone().unwrap_or_default(),
+           query_error: query.clone().err(),
+       }
+       }
+"###;
@@ -0,0 +1,97 @@
+//! Navigation menu with a link to every route without payload.
+
+use crate::{
+    content::{
+        get_routes,
+        module::templates::nav::{_NAV_GUARDED_LINK_TEMPLATE, _NAV_LINK_TEMPLATE, _NAV_TEMPLATE},
+        ContentSettings,
+    },
+    parser::{route::is_default_route, view::get_guard_attribute},
+};
+use convert_case::{Case, Casing};
+use syn::{Fields, ItemEnum, ItemStruct};
+
+/// The `nav` function with a link to every unit variant of the Routes enum but
+/// the default route. The guarded links are hidden when the guard does not
+/// allow the route.
+pub fn get_nav(routes_enum: &ItemEnum, model: &ItemStruct, settings: &ContentSettings,) -> String {
+    let routes = get_routes(routes_enum,);
+    let mut uses_model = false;
+    let mut links = String::new();
+    for v in routes_enum.variants.iter() {
+        if !matches!(v.fields, Fields::Unit) || is_default_route(v.attrs.iter(),) {
+            continue;
+        }
+        let name = v.ident.to_string();
+        let condition = get_guard_attribute(v.ident.clone(), v.attrs.iter(),).and_then(
+            |(scope, guard, _,)| {
+                let call = settings.index.scoped_call(&guard, model, &scope,);
+                settings.guard_return.allows(&call,)
+            },
+        );
+        let link = match condition {
+            Some(condition,) => {
+                uses_model = true;
+                _NAV_GUARDED_LINK_TEMPLATE.replace("CONDITION", &condition,)
+            },
+            None => _NAV_LINK_TEMPLATE.to_string(),
+        };
+        links.push_str(
+            &link
+                .replace("URL", &routes[&name].url_path(),)
+                .replace("ROUTE", &name,)
+                .replace("LABEL", &name.to_case(Case::Title,),),
+        );
+    }
+    settings
+        .visibilities
+        .views
+        .apply(_NAV_TEMPLATE,)
+        .replace("MODEL", if uses_model { "model" } else { "_model" },)
+        .replace("LINKS", &links,)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        constants::_FILE_WITH_ROUTES_AND_MODEL,
+        content::{nav::get_nav, ContentSettings},
+        find_model, find_routes,
+    };
+
+    #[test]
+    fn test_get_nav() {
+        let file = _FILE_WITH_ROUTES_AND_MODEL.replace(
+            "    #[view = \"theme => home\"]",
+            "    #[guard = \"logged_user => user_guard => forbidden\"]\n    #[view = \"theme => \
+             home\"]",
+        );
+        let parsed_file = syn::parse_file(&file,).unwrap();
+        let model = find_model(&parsed_file,).unwrap();
+        let routes_enum = find_routes(&parsed_file,).unwrap();
+
+        let nav = get_nav(&routes_enum, &model, &ContentSettings::default(),);
+
+        assert!(nav.starts_with("/// Link to every route"));
+        assert!(nav.contains("\nfn nav(model: &Model,) -> Node<Msg,> {\n"));
+        assert!(nav.contains(
+            "        // -> /forbidden\n        li![a![attrs! {At::Href => \
+             Routes::Forbidden.to_url()}, \"Forbidden\"]],\n"
+        ));
+        assert!(nav.contains(
+            "        // -> /\n        IF!(user_guard(&model.logged_user,) == Some(true,) => \
+             li![a![attrs! {At::Href => Routes::Home.to_url()}, \"Home\"]]),\n"
+        ));
+        assert!(nav.contains("IF!(guard(model,) == Some(true,) => li![a![attrs! {At::Href => \
+                              Routes::Settings.to_url()}"));
+        assert!(!nav.contains("NotFound"));
+        assert!(!nav.contains("Admin"));
+
+        let settings = ContentSettings {
+            guard_return: "bool".parse().unwrap(),
+            ..ContentSettings::default()
+        };
+        assert!(get_nav(&routes_enum, &model, &settings,)
+            .contains("IF!(user_guard(&model.logged_user,) => li!"));
+    }
+}
@@ -0,0 +1,178 @@
+//! Write the unit tests checking that every variant of the Routes enum is
+//! parsed from its url and converted back to it.
+
+use crate::content::{
+    module::templates::test::{
+        _QUERY_TEST_TEMPLATE, _ROUTES_TEST_COMMENT, _ROUTES_TEST_TEMPLATE, _ROUTE_TEST_TEMPLATE,
+    },
+    SeedRoute,
+};
+use convert_case::{Case, Casing};
+use indexmap::map::IndexMap;
+use syn::{export::ToTokens, Fields, ItemEnum, Type, Variant};
+
+/// Get the routing tests for the Routes enum.
+/// `nested_routes` has the variants of the nested `Routes` enums by parent
+/// variant name, a variant with children is skipped when its nested routes
+/// are unknown.
+pub fn get_routes_tests(
+    routes_enum: &ItemEnum,
+    routes: &IndexMap<String, SeedRoute,>,
+    nested_routes: &IndexMap<String, IndexMap<String, SeedRoute,>,>,
+) -> String {
+    let mut tests = "".to_string();
+    for v in routes_enum.variants.iter() {
+        let route = match routes.get(&v.ident.to_string(),) {
+            Some(route,) => route,
+            None => continue,
+        };
+        let child = if route.nested || route.children {
+            match nested_routes.get(&route.name,).and_then(get_child_route,) {
+                Some(child,) => Some(child,),
+                None => {
+                    tests = format!(
+                        "{}\n    // nested routes for {} not found, write its test by hand.\n",
+                        tests, route.name
+                    );
+                    continue;
+                },
+            }
+        } else {
+            None
+        };
+        match get_route_test(v, route, child,) {
+            Some(test,) => tests = format!("{}{}", tests, test),
+            None => {
+                tests = format!(
+                    "{}\n    // {} has unsupported fields, write its test by hand.\n",
+                    tests, route.name
+                );
+            },
+        }
+    }
+    format!(
+        "{}{}",
+        _ROUTES_TEST_COMMENT,
+        _ROUTES_TEST_TEMPLATE.replace("TESTS", tests.as_str(),)
+    )
+}
+
+/// Pick a variant without payload from the nested routes so the test can
+/// build it.
+fn get_child_route(routes: &IndexMap<String, SeedRoute,>,) -> Option<&SeedRoute,> {
+    routes.values().find(|r| {
+        !r.id_param && !r.query && !r.children && !r.nested && !r.path_segment().is_empty()
+    },)
+}
+
+fn get_route_test(v: &Variant, route: &SeedRoute, child: Option<&SeedRoute,>,) -> Option<String,> {
+    let child_value = |ty: &Type| {
+        let mut nested_type = ty.to_token_stream().to_string();
+        nested_type.retain(|c| !c.is_whitespace(),);
+        format!("{}::{}", nested_type, child.map(|c| c.name.as_str()).unwrap_or_default())
+    };
+
+    let value = match &v.fields {
+        Fields::Unit => format!("Routes::{}", route.name),
+        Fields::Unnamed(fields,) => {
+            let field = fields.unnamed.first()?;
+            format!("Routes::{}({})", route.name, child_value(&field.ty))
+        },
+        Fields::Named(fields,) => {
+            let mut values: Vec<String,> = Vec::new();
+            for field in fields.named.iter() {
+                let name = field.ident.as_ref()?.to_string();
+                match name.as_str() {
+                    "id" => values.push("id: \"1\".to_string()".to_string(),),
+                    "query" => values.push("query".to_string(),),
+                    "children" => {
+                        values.push(format!("children: {}", child_value(&field.ty)),)
+                    },
+                    _ => return None,
+                }
+            }
+            format!("Routes::{} {{ {} }}", route.name, values.join(", "))
+        },
+    };
+
+    let mut parts: Vec<String,> = Vec::new();
+    let segment = route.path_segment();
+    if !segment.is_empty() {
+        parts.push(segment,);
+    }
+    if route.id_param {
+        parts.push("1".to_string(),);
+    }
+    if let Some(child,) = child {
+        parts.push(child.path_segment(),);
+    }
+    let mut url = format!("/{}", parts.join("/"));
+    if route.query {
+        url = format!("{}?name=JohnDoe", url);
+    }
+
+    let query = if route.query { _QUERY_TEST_TEMPLATE } else { "" };
+    Some(
+        _ROUTE_TEST_TEMPLATE
+            .replace("NAME", route.name.to_case(Case::Snake,).as_str(),)
+            .replace("QUERY", query,)
+            .replace("ROUTE", value.as_str(),)
+            .replace("URL", url.as_str(),),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        constants::_FILE_WITH_ROUTES_AND_MODEL,
+        content::{get_routes, routing_test::get_routes_tests},
+        find_routes,
+    };
+    use indexmap::map::IndexMap;
+
+    const ADMIN_ROUTES: &str = r###"
+#[derive(Debug, PartialEq, Clone, RoutingModules)]
+pub enum Routes {
+    Root,
+    Manager,
+}
+"###;
+
+    const ADMIN_TEST: &str = r###"
+    #[test]
+    fn test_admin_route() {
+        let route = Routes::Admin { id: "1".to_string(), children: pages::admin::Routes::Root };
+        assert_eq!(Routes::parse_path("/admin/1/root").unwrap(), route);
+        assert_eq!(route.as_path(), "/admin/1/root");
+    }
+"###;
+
+    const HOME_TEST: &str = r###"
+    #[test]
+    fn test_home_route() {
+        let route = Routes::Home;
+        assert_eq!(Routes::parse_path("/").unwrap(), route);
+        assert_eq!(route.as_path(), "/");
+    }
+"###;
+
+    #[test]
+    fn test_get_routes_tests() {
+        let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+        let routes_enum = find_routes(&parsed_file,).unwrap();
+        let parsed_admin = syn::parse_file(ADMIN_ROUTES,).unwrap();
+        let mut nested_routes = IndexMap::new();
+        nested_routes.insert(
+            "Admin".to_string(),
+            get_routes(&find_routes(&parsed_admin,).unwrap(),),
+        );
+
+        let result = get_routes_tests(&routes_enum, &get_routes(&routes_enum,), &nested_routes,);
+
+        assert!(result.contains("#[cfg(test)]\nmod routes_test {\n    use super::*;\n"));
+        assert!(result.contains(ADMIN_TEST));
+        assert!(result.contains(HOME_TEST));
+        assert!(result.contains("Routes::parse_path(\"/login?name=JohnDoe\")"));
+        assert!(result.contains("// nested routes for Dashboard not found, write its test by hand."));
+    }
+}
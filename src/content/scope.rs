@@ -0,0 +1,513 @@
+//! Resolve the Model scopes of the guards and views, such as `session.user`,
+//! through the structs declared in the modules of the crate.
+
+use crate::parser::find_imports;
+use syn::{
+    export::ToTokens, GenericArgument, ImplItem, Item, ItemImpl, ItemMod, ItemStruct, PathArguments,
+    Type, TypePath,
+};
+
+/// The items of a module of the crate needed to resolve a type.
+#[derive(Clone, Default)]
+pub struct CrateModule {
+    /// Path from the crate root, empty for the root.
+    pub path: Vec<String,>,
+    pub structs: Vec<ItemStruct,>,
+    /// Names of the enums declared in the module.
+    pub enums: Vec<String,>,
+    /// `use` declarations as alias -> path, `*` for globs.
+    pub imports: Vec<(String, Vec<String,>,),>,
+    /// Methods of the `impl` blocks as type path, as written in the module,
+    /// -> method name.
+    pub methods: Vec<(Vec<String,>, String,),>,
+}
+
+/// The modules of the crate, the target file being the crate root.
+#[derive(Clone, Default)]
+pub struct CrateIndex {
+    pub modules: Vec<CrateModule,>,
+}
+
+/// The field of the Model reached from a scope.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ScopedField {
+    /// Name of the last field of the scope, used as argument name.
+    pub ident: String,
+    /// Its type without whitespace, qualified from the crate root when it is
+    /// written in another module than the target file.
+    pub ty: String,
+}
+
+/// The first field of a scope missing on the Model or on its structs.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MissingField {
+    /// The part of the scope that exists, empty when the field is missing on
+    /// the Model.
+    pub parent: String,
+    pub name: String,
+    /// The fields of the parent, empty when the parent is not a struct of the
+    /// crate.
+    pub available: Vec<String,>,
+}
+
+impl MissingField {
+    /// The closest existing scope, `session.usr` -> `session.user`.
+    pub fn suggestion(&self,) -> Option<String,> {
+        let closest = suggest(&self.name, &self.available,)?;
+        if self.parent.is_empty() {
+            Some(closest,)
+        } else {
+            Some(format!("{}.{}", self.parent, closest),)
+        }
+    }
+}
+
+/// Maximum number of `use` declarations to follow, to stop on cycles.
+const MAX_DEPTH: usize = 16;
+
+impl CrateIndex {
+    /// Index the items of the module and of its inline modules.
+    pub fn add_module(&mut self, path: Vec<String,>, items: &[Item],) {
+        let mut module = CrateModule {
+            path: path.clone(),
+            imports: find_imports(items,),
+            ..CrateModule::default()
+        };
+        for item in items {
+            match item {
+                Item::Struct(found_struct,) => module.structs.push(found_struct.clone(),),
+                Item::Enum(found_enum,) => module.enums.push(found_enum.ident.to_string(),),
+                Item::Impl(ItemImpl {
+                    trait_: None,
+                    self_ty,
+                    items,
+                    ..
+                },) => {
+                    if let Type::Path(TypePath {
+                        path, ..
+                    },) = self_ty.as_ref()
+                    {
+                        let type_path: Vec<String,> =
+                            path.segments.iter().map(|s| s.ident.to_string(),).collect();
+                        for method in items.iter() {
+                            if let ImplItem::Method(method,) = method {
+                                module
+                                    .methods
+                                    .push((type_path.clone(), method.sig.ident.to_string(),),);
+                            }
+                        }
+                    }
+                },
+                Item::Mod(ItemMod {
+                    ident,
+                    content: Some((_, items,),),
+                    ..
+                },) => {
+                    let mut child = path.clone();
+                    child.push(ident.to_string(),);
+                    self.add_module(child, items,);
+                },
+                _ => {},
+            }
+        }
+        self.modules.push(module,);
+    }
+
+    /// Check if an inherent `impl` of the crate gives this method to the type
+    /// declared in `module`, the type of each `impl` being resolved from its
+    /// own module so a struct with the same name elsewhere does not match.
+    pub fn has_method(&self, module: &[String], type_name: &str, method: &str,) -> bool {
+        self.modules.iter().any(|m| {
+            m.methods.iter().any(|(type_path, found,)| {
+                found == method
+                    && self.resolve(&m.path, type_path, 0,)
+                        == Some((module.to_vec(), type_name.to_string(),),)
+            },)
+        },)
+    }
+
+    fn module(&self, path: &[String],) -> Option<&CrateModule,> {
+        self.modules.iter().find(|m| m.path == path,)
+    }
+
+    /// Find the module declaring the struct or enum written as `segments` in
+    /// `module`, following the `use` declarations.
+    fn resolve(
+        &self,
+        module: &[String],
+        segments: &[String],
+        depth: usize,
+    ) -> Option<(Vec<String,>, String,),> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let (first, rest,) = segments.split_first()?;
+        let current = self.module(module,)?;
+        if rest.is_empty() {
+            if current.structs.iter().any(|s| s.ident == first,)
+                || current.enums.iter().any(|e| e == first,)
+            {
+                return Some((module.to_vec(), first.clone(),),);
+            }
+            if let Some((_, path,),) = current.imports.iter().find(|(alias, _,)| alias == first,) {
+                return self.resolve(module, path, depth + 1,);
+            }
+            return current
+                .imports
+                .iter()
+                .filter(|(alias, _,)| alias == "*",)
+                .find_map(|(_, path,)| {
+                    let mut glob = path.clone();
+                    glob.push(first.clone(),);
+                    self.resolve(module, &glob, depth + 1,)
+                },);
+        }
+        match first.as_str() {
+            "crate" => self.resolve(&[], rest, depth + 1,),
+            "self" => self.resolve(module, rest, depth + 1,),
+            "super" if !module.is_empty() => {
+                self.resolve(&module[..module.len() - 1], rest, depth + 1,)
+            },
+            "super" => None,
+            _ => {
+                let mut child = module.to_vec();
+                child.push(first.clone(),);
+                if self.module(&child,).is_some() {
+                    return self.resolve(&child, rest, depth + 1,);
+                }
+                let (_, path,) = current.imports.iter().find(|(alias, _,)| alias == first,)?;
+                let mut full_path = path.clone();
+                full_path.extend_from_slice(rest,);
+                self.resolve(module, &full_path, depth + 1,)
+            },
+        }
+    }
+
+    /// Find the struct written as `type_path` in `module` with the module
+    /// declaring it. Fall back on the only struct with this name in the crate.
+    pub fn resolve_struct(
+        &self,
+        module: &[String],
+        type_path: &str,
+    ) -> Option<(Vec<String,>, &ItemStruct,),> {
+        let segments: Vec<String,> = type_path.split("::",).map(str::to_string,).collect();
+        let find = |path: &[String], name: &str| {
+            self.module(path,)
+                .and_then(|m| m.structs.iter().find(|s| s.ident == name,),)
+                .map(|found| (path.to_vec(), found,),)
+        };
+        if let Some((path, name,),) = self.resolve(module, &segments, 0,) {
+            return find(&path, &name,);
+        }
+        let name = segments.last()?;
+        let mut found = self
+            .modules
+            .iter()
+            .filter_map(|m| m.structs.iter().find(|s| s.ident == name,).map(|s| (m, s,),),);
+        match (found.next(), found.next(),) {
+            (Some((m, s,),), None,) => Some((m.path.clone(), s,),),
+            _ => None,
+        }
+    }
+
+    /// Write the type as seen from the target file, the structs and enums of
+    /// other modules get their path from the crate root.
+    pub fn qualify(&self, module: &[String], ty: &Type,) -> String {
+        let mut ty = ty.clone();
+        if !module.is_empty() {
+            self.qualify_type(module, &mut ty,);
+        }
+        let mut written = ty.to_token_stream().to_string();
+        written.retain(|c| !c.is_whitespace(),);
+        written
+    }
+
+    fn qualify_type(&self, module: &[String], ty: &mut Type,) {
+        match ty {
+            Type::Path(type_path,) if type_path.qself.is_none() => {
+                for segment in type_path.path.segments.iter_mut() {
+                    if let PathArguments::AngleBracketed(args,) = &mut segment.arguments {
+                        for arg in args.args.iter_mut() {
+                            if let GenericArgument::Type(ty,) = arg {
+                                self.qualify_type(module, ty,);
+                            }
+                        }
+                    }
+                }
+                let segments: Vec<String,> = type_path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|s| s.ident.to_string(),)
+                    .collect();
+                if let Some((path, name,),) = self.resolve(module, &segments, 0,) {
+                    let qualified = if path.is_empty() {
+                        name
+                    } else {
+                        format!("crate::{}::{}", path.join("::"), name)
+                    };
+                    if let Ok(mut new_path,) = syn::parse_str::<syn::Path,>(&qualified,) {
+                        if let (Some(last,), Some(old,),) =
+                            (new_path.segments.last_mut(), type_path.path.segments.last(),)
+                        {
+                            last.arguments = old.arguments.clone();
+                        }
+                        type_path.path = new_path;
+                    }
+                }
+            },
+            Type::Reference(reference,) => self.qualify_type(module, &mut reference.elem,),
+            Type::Slice(slice,) => self.qualify_type(module, &mut slice.elem,),
+            Type::Array(array,) => self.qualify_type(module, &mut array.elem,),
+            Type::Tuple(tuple,) => {
+                for elem in tuple.elems.iter_mut() {
+                    self.qualify_type(module, elem,);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// Follow the dotted scope from the Model, `session.user` is the field
+    /// `user` of the struct of the field `session`.
+    pub fn resolve_scope(&self, model: &ItemStruct, scope: &str,) -> Option<ScopedField,> {
+        self.find_scope(model, scope,).ok()
+    }
+
+    /// Call the function the way the generated guards and views take their
+    /// scope, `&model.scope` when it resolves and `model` otherwise.
+    pub fn scoped_call(&self, function: &str, model: &ItemStruct, scope: &str,) -> String {
+        if !scope.is_empty() && self.resolve_scope(model, scope,).is_some() {
+            format!("{}(&model.{},)", function, scope)
+        } else {
+            format!("{}(model,)", function)
+        }
+    }
+
+    /// Same as `resolve_scope` with the field that is missing on error.
+    pub fn find_scope(
+        &self,
+        model: &ItemStruct,
+        scope: &str,
+    ) -> Result<ScopedField, MissingField,> {
+        let mut current_fields = Some(&model.fields,);
+        let mut module: Vec<String,> = Vec::new();
+        let mut parent: Vec<&str,> = Vec::new();
+        let mut names = scope.split('.',).map(str::trim,).peekable();
+        while let Some(name,) = names.next() {
+            let missing = |available: Vec<String,>| MissingField {
+                parent: parent.join(".",),
+                name: name.to_string(),
+                available,
+            };
+            let fields = current_fields.ok_or_else(|| missing(Vec::new(),),)?;
+            let field = fields
+                .iter()
+                .find(|f| f.ident.as_ref().map(|i| i == name,).unwrap_or(false,),)
+                .ok_or_else(|| {
+                    missing(
+                        fields
+                            .iter()
+                            .filter_map(|f| f.ident.as_ref().map(|i| i.to_string(),),)
+                            .collect(),
+                    )
+                },)?;
+            if names.peek().is_none() {
+                return Ok(ScopedField {
+                    ident: name.to_string(),
+                    ty: self.qualify(&module, &field.ty,),
+                },);
+            }
+            parent.push(name,);
+            let found = match &field.ty {
+                Type::Path(type_path,) if type_path.qself.is_none() => {
+                    let type_path = type_path
+                        .path
+                        .segments
+                        .iter()
+                        .map(|s| s.ident.to_string(),)
+                        .collect::<Vec<String,>>()
+                        .join("::",);
+                    self.resolve_struct(&module, &type_path,)
+                },
+                _ => None,
+            };
+            current_fields = match found {
+                Some((struct_module, found,),) => {
+                    module = struct_module;
+                    Some(&found.fields,)
+                },
+                None => None,
+            };
+        }
+        Err(MissingField {
+            parent: String::new(),
+            name: scope.to_string(),
+            available: Vec::new(),
+        },)
+    }
+}
+
+/// The closest candidate within a third of the length of the name, as typos
+/// rarely change more.
+pub fn suggest(name: &str, candidates: &[String],) -> Option<String,> {
+    let max_distance = std::cmp::max(name.chars().count(), 3,) / 3;
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate,), candidate,),)
+        .filter(|(distance, _,)| *distance <= max_distance,)
+        .min_by_key(|(distance, _,)| *distance,)
+        .map(|(_, candidate,)| candidate.clone(),)
+}
+
+/// Levenshtein distance between the two strings.
+fn edit_distance(a: &str, b: &str,) -> usize {
+    let b: Vec<char,> = b.chars().collect();
+    let mut previous: Vec<usize,> = (0..=b.len()).collect();
+    for (i, a_char,) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char,) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == *b_char { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1,).min(current[j] + 1,),);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::content::scope::{edit_distance, suggest, CrateIndex, MissingField, ScopedField};
+
+    const SESSION: &str = r###"
+use crate::session::Session;
+struct Model {
+    session: Session,
+    logged_user: Option<LoggedData>,
+}
+struct LoggedData {}
+impl session::Session {
+    pub fn user(&self,) -> Option<&LoggedData,> {
+        None
+    }
+}
+mod session {
+    use super::user::{LoggedData, Role as UserRole};
+    pub struct Session {
+        pub user: Option<LoggedData>,
+        pub roles: Vec<UserRole>,
+        pub settings: settings::Settings,
+    }
+    pub mod settings {
+        pub struct Settings {
+            pub dark_mode: bool,
+        }
+    }
+}
+mod user {
+    pub struct LoggedData {
+        pub role: Role,
+    }
+    pub enum Role {
+        Admin,
+    }
+    impl LoggedData {
+        pub fn is_admin(&self,) -> bool {
+            true
+        }
+    }
+}
+"###;
+
+    fn get_index() -> (CrateIndex, syn::ItemStruct,) {
+        let parsed_file = syn::parse_file(SESSION,).unwrap();
+        let mut index = CrateIndex::default();
+        index.add_module(Vec::new(), &parsed_file.items,);
+        (index, crate::find_model(&parsed_file,).unwrap(),)
+    }
+
+    #[test]
+    fn test_resolve_scope() {
+        let (index, model,) = get_index();
+        let scoped = |scope: &str| index.resolve_scope(&model, scope,);
+
+        assert_eq!(
+            scoped("logged_user"),
+            Some(ScopedField {
+                ident: "logged_user".to_string(),
+                ty: "Option<LoggedData>".to_string()
+            })
+        );
+        assert_eq!(
+            scoped("session.user"),
+            Some(ScopedField {
+                ident: "user".to_string(),
+                ty: "Option<crate::user::LoggedData>".to_string()
+            })
+        );
+        assert_eq!(
+            scoped("session.roles").unwrap().ty,
+            "Vec<crate::user::Role>"
+        );
+        assert_eq!(scoped("session.settings.dark_mode").unwrap().ty, "bool");
+        assert_eq!(scoped("session.unknown"), None);
+        assert_eq!(scoped("logged_user.role"), None);
+    }
+
+    #[test]
+    fn test_resolve_struct() {
+        let (index, _,) = get_index();
+        let module = vec!["session".to_string()];
+
+        let (path, found,) = index.resolve_struct(&module, "LoggedData",).unwrap();
+        assert_eq!(path, vec!["user"]);
+        assert_eq!(found.ident, "LoggedData");
+        assert_eq!(index.resolve_struct(&[], "Session",).unwrap().0, vec!["session"]);
+        assert_eq!(
+            index.resolve_struct(&[], "crate::session::settings::Settings",).unwrap().0,
+            vec!["session", "settings"]
+        );
+        assert!(index.resolve_struct(&[], "Session2",).is_none());
+    }
+
+    #[test]
+    fn test_has_method() {
+        let (index, _,) = get_index();
+
+        assert!(index.has_method(&["user".to_string()], "LoggedData", "is_admin",));
+        assert!(!index.has_method(&[], "LoggedData", "is_admin",));
+        assert!(index.has_method(&["session".to_string()], "Session", "user",));
+        assert!(!index.has_method(&["session".to_string()], "Session", "roles",));
+    }
+
+    #[test]
+    fn test_find_scope_missing_field() {
+        let (index, model,) = get_index();
+
+        let missing = index.find_scope(&model, "session.usr",).unwrap_err();
+        assert_eq!(missing, MissingField {
+            parent: "session".to_string(),
+            name: "usr".to_string(),
+            available: vec!["user".to_string(), "roles".to_string(), "settings".to_string()],
+        });
+        assert_eq!(missing.suggestion(), Some("session.user".to_string()));
+
+        let missing = index.find_scope(&model, "loged_user",).unwrap_err();
+        assert_eq!(missing.suggestion(), Some("logged_user".to_string()));
+
+        let missing = index.find_scope(&model, "logged_user.role",).unwrap_err();
+        assert_eq!(missing.parent, "logged_user");
+        assert!(missing.available.is_empty());
+        assert_eq!(missing.suggestion(), None);
+    }
+
+    #[test]
+    fn test_suggest() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        let fields = vec!["logged_user".to_string(), "session".to_string()];
+        assert_eq!(suggest("loged_usr", &fields), Some("logged_user".to_string()));
+        assert_eq!(suggest("sesion", &fields), Some("session".to_string()));
+        assert_eq!(suggest("admin", &fields), None);
+    }
+}
@@ -1,10 +1,13 @@
 //! Get local views to write in SeedContent.
 use crate::{
-    content::{get_scoped_field, SeedRoute},
-    parser::view::get_view_attribute,
+    content::{scope::CrateIndex, ContentSettings, SeedRoute},
+    parser::{
+        route::{get_as_path_attribute, is_default_route},
+        view::get_view_attribute,
+    },
 };
 use indexmap::map::IndexMap;
-use syn::{export::ToTokens, ItemEnum, ItemStruct};
+use syn::{ItemEnum, ItemStruct};
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct SeedView {
@@ -16,25 +19,38 @@ pub struct SeedView {
     pub(crate) route: SeedRoute,
 }
 
-pub fn get_local_views(routes_enum: &ItemEnum, model: ItemStruct,) -> IndexMap<String, SeedView,> {
+pub fn get_local_views(
+    routes_enum: &ItemEnum,
+    model: ItemStruct,
+    settings: &ContentSettings,
+) -> IndexMap<String, SeedView,> {
     let mut map: IndexMap<String, SeedView,> = IndexMap::new();
 
     for v in routes_enum.variants.iter() {
         if let Some((model_scope, view,),) = get_view_attribute(v.ident.clone(), v.attrs.iter(),) {
-            let function_content = get_view_function(model_scope.as_str(), view.as_str(), &model,);
+            let mut route = SeedRoute {
+                name: v.ident.clone().to_string(),
+                content_to_load: "".to_string(),
+                nested: false,
+                children: false,
+                id_param: false,
+                query: false,
+                as_path: get_as_path_attribute(v.attrs.iter(),),
+                default_route: is_default_route(v.attrs.iter(),),
+            };
+            let function_content = format!(
+                "// -> {}\n{}{}",
+                route.url_path(),
+                settings.visibilities.views.prefix(),
+                get_view_function(model_scope.as_str(), view.as_str(), &model, &settings.index,)
+            );
+            route.content_to_load = function_content.clone();
             map.insert(
                 view.clone(),
                 SeedView {
                     name: view,
-                    content: function_content.clone(),
-                    route: SeedRoute {
-                        name: v.ident.clone().to_string(),
-                        content_to_load: function_content.clone(),
-                        nested: false,
-                        children: false,
-                        id_param: false,
-                        query: false,
-                    },
+                    content: function_content,
+                    route,
                 },
             );
         }
@@ -42,42 +58,29 @@ pub fn get_local_views(routes_enum: &ItemEnum, model: ItemStruct,) -> IndexMap<S
     map
 }
 
-/// todo add Model extractor to match the scope
-pub fn get_view_function(model_scope: &str, view: &str, model: &ItemStruct,) -> String {
+/// The scope can be a dotted path through the structs of the crate such as
+/// `session.user`.
+pub fn get_view_function(
+    model_scope: &str,
+    view: &str,
+    model: &ItemStruct,
+    index: &CrateIndex,
+) -> String {
     if model_scope.is_empty() {
         format!(
             "fn {}(model : &Model) -> Node<Msg>{{div![\"{}\"]}}",
             view, view
         )
+    } else if let Some(s,) = index.resolve_scope(model, model_scope,) {
+        format!(
+            "fn {}({} : &{}) -> Node<Msg>{{div![\"{}\"]}}",
+            view, s.ident, s.ty, view
+        )
     } else {
-        let scope = model
-            .fields
-            .iter()
-            .find(|field| get_scoped_field(model_scope.to_string(), field,),);
-        // fix it with Model
-
-        if let Some(s,) = scope {
-            let scope_type = &mut s.ty.to_token_stream().to_string();
-            scope_type.retain(|c| !c.is_whitespace(),);
-
-            let ident = &s.ident.as_ref().expect("Should have get property name",);
-            format!(
-                "fn {}({} : &{}) -> Node<Msg>{{div![\"{}\"]}}",
-                view,
-                ident.to_string(),
-                scope_type,
-                view
-            )
-        } else {
-            println!(
-                "scope {} not found on Model {} so we inject Model instead",
-                model_scope, model.ident
-            );
-            format!(
-                "fn {}(model : &Model) -> Node<Msg>{{div![\"{}\"]}}",
-                view, view
-            )
-        }
+        format!(
+            "fn {}(model : &Model) -> Node<Msg>{{div![\"{}\"]}}",
+            view, view
+        )
     }
 }
 
@@ -85,22 +88,25 @@ pub fn get_view_function(model_scope: &str, view: &str, model: &ItemStruct,) ->
 mod test {
     use crate::{
         constants::_FILE_WITH_ROUTES_AND_MODEL,
-        content::{view::get_view_function, SeedContent},
-        find_model, find_routes,
+        content::{scope::CrateIndex, view::get_view_function},
+        find_model,
     };
-    use indexmap::map::IndexMap;
 
     const FORBIDDEN_VIEW: &str =
         r###"fn forbidden(logged_user : &Option<LoggedData>) -> Node<Msg>{div!["forbidden"]}"###;
 
     const NOT_FOUND: &str = r###"fn not_found(model : &Model) -> Node<Msg>{div!["not_found"]}"###;
-    const HOME: &str = r###"fn home(theme : &Theme) -> Node<Msg>{div!["home"]}"###;
     #[test]
     fn test_get_view_function_when_scope_is_good() {
         let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_AND_MODEL,).unwrap();
         let model = find_model(&parsed_file,);
 
-        let result = get_view_function("logged_user", "forbidden", &model.unwrap(),);
+        let result = get_view_function(
+            "logged_user",
+            "forbidden",
+            &model.unwrap(),
+            &CrateIndex::default(),
+        );
 
         let should_have = FORBIDDEN_VIEW;
 
@@ -112,7 +118,12 @@ mod test {
         let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_AND_MODEL,).unwrap();
         let model = find_model(&parsed_file,);
 
-        let result = get_view_function("my_wrong_scope_on_model", "forbidden", &model.unwrap(),);
+        let result = get_view_function(
+            "my_wrong_scope_on_model",
+            "forbidden",
+            &model.unwrap(),
+            &CrateIndex::default(),
+        );
 
         let should_have = r###"fn forbidden(model : &Model) -> Node<Msg>{div!["forbidden"]}"###;
 
@@ -124,7 +135,12 @@ mod test {
         let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_AND_MODEL,).unwrap();
         let model = find_model(&parsed_file,);
 
-        let result = get_view_function("", "not_found", &model.unwrap(),);
+        let result = get_view_function(
+            "",
+            "not_found",
+            &model.unwrap(),
+            &CrateIndex::default(),
+        );
 
         let should_have = NOT_FOUND;
 
@@ -0,0 +1,170 @@
+//! Visibility of the generated items, configurable per kind of item.
+
+use std::{fmt, str::FromStr};
+
+/// Placeholder in the templates replaced by the visibility of the item.
+pub const _VISIBILITY: &str = "VISIBILITY ";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Visibility {
+    /// `pub`
+    Public,
+    /// `pub(crate)`
+    Crate,
+    /// No visibility keyword.
+    Private,
+}
+
+impl Visibility {
+    /// The keyword to write before the item, with its trailing space.
+    pub fn prefix(&self,) -> &'static str {
+        match self {
+            Visibility::Public => "pub ",
+            Visibility::Crate => "pub(crate) ",
+            Visibility::Private => "",
+        }
+    }
+
+    /// Replace the `VISIBILITY` placeholder of the template.
+    pub fn apply(&self, template: &str,) -> String {
+        template.replace(_VISIBILITY, self.prefix(),)
+    }
+}
+
+impl FromStr for Visibility {
+    type Err = String;
+
+    fn from_str(s: &str,) -> Result<Self, Self::Err,> {
+        match s {
+            "pub" => Ok(Visibility::Public,),
+            "pub(crate)" | "crate" => Ok(Visibility::Crate,),
+            "private" | "" => Ok(Visibility::Private,),
+            _ => Err(format!(
+                "unknown visibility {}, expected pub, pub(crate) or private",
+                s
+            ),),
+        }
+    }
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_,>,) -> fmt::Result {
+        match self {
+            Visibility::Public => write!(f, "pub"),
+            Visibility::Crate => write!(f, "pub(crate)"),
+            Visibility::Private => write!(f, "private"),
+        }
+    }
+}
+
+/// The visibility of every kind of generated item.
+/// The default keeps what the parent module needs to reach:
+/// - `mod login;` private in the target file.
+/// - `pub mod login;` in the folder module so the target file can use it.
+/// - `pub` TEA items so the parent can call init, update and view.
+/// - private guards and views as they are only used by the target file.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Visibilities {
+    /// Module declarations written in the target file.
+    pub modules: Visibility,
+    /// Module declarations written in the folder module from
+    /// `#[modules_path = "pages"]`.
+    pub folder_modules: Visibility,
+    /// init, Model, Routes, Msg, update and view of each module.
+    pub tea: Visibility,
+    /// Guards on the target file.
+    pub guards: Visibility,
+    /// Local views and guard redirects on the target file.
+    pub views: Visibility,
+}
+
+impl Default for Visibilities {
+    fn default() -> Self {
+        Visibilities {
+            modules: Visibility::Private,
+            folder_modules: Visibility::Public,
+            tea: Visibility::Public,
+            guards: Visibility::Private,
+            views: Visibility::Private,
+        }
+    }
+}
+
+impl Visibilities {
+    /// Set the visibility from a `kind=visibility` rule such as
+    /// `guard=pub(crate)`.
+    pub fn set_rule(&mut self, rule: &str,) -> Result<&mut Self, String,> {
+        let (kind, visibility,) = match rule.find('=',) {
+            Some(index,) => (&rule[..index], &rule[index + 1..],),
+            None => return Err(format!("expected kind=visibility, got {}", rule),),
+        };
+        let visibility = visibility.parse::<Visibility>()?;
+        match kind {
+            "mod" => self.modules = visibility,
+            "folder_mod" => self.folder_modules = visibility,
+            "tea" => self.tea = visibility,
+            "guard" => self.guards = visibility,
+            "view" => self.views = visibility,
+            _ => {
+                return Err(format!(
+                    "unknown item kind {}, expected mod, folder_mod, tea, guard or view",
+                    kind
+                ),)
+            },
+        }
+        Ok(self,)
+    }
+}
+
+/// Parse comma separated rules on top of the default, such as
+/// `guard=pub,view=pub(crate)`.
+impl FromStr for Visibilities {
+    type Err = String;
+
+    fn from_str(s: &str,) -> Result<Self, Self::Err,> {
+        let mut visibilities = Visibilities::default();
+        for rule in s.split(',',).map(str::trim,).filter(|rule| !rule.is_empty(),) {
+            visibilities.set_rule(rule,)?;
+        }
+        Ok(visibilities,)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::content::visibility::{Visibilities, Visibility};
+
+    #[test]
+    fn test_visibility() {
+        assert_eq!("pub(crate)".parse::<Visibility>(), Ok(Visibility::Crate));
+        assert_eq!(
+            Visibility::Crate.apply(" VISIBILITY fn init() {}"),
+            " pub(crate) fn init() {}"
+        );
+        assert_eq!(Visibility::Private.apply("VISIBILITY struct Model {}"), "struct Model {}");
+        assert!("protected".parse::<Visibility>().is_err());
+    }
+
+    #[test]
+    fn test_set_rule() {
+        let mut visibilities = Visibilities::default();
+        visibilities
+            .set_rule("guard=pub",)
+            .unwrap()
+            .set_rule("tea=pub(crate)",)
+            .unwrap();
+
+        assert_eq!(visibilities.guards, Visibility::Public);
+        assert_eq!(visibilities.tea, Visibility::Crate);
+        assert_eq!(visibilities.views, Visibility::Private);
+        assert!(visibilities.set_rule("guard",).is_err());
+        assert!(visibilities.set_rule("trait=pub",).is_err());
+
+        let parsed = "guard=pub, tea=pub(crate)".parse::<Visibilities>().unwrap();
+        assert_eq!(parsed, Visibilities {
+            guards: Visibility::Public,
+            tea: Visibility::Crate,
+            ..Visibilities::default()
+        });
+    }
+}
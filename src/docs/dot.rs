@@ -0,0 +1,144 @@
+//! Route tree as a Graphviz DOT graph.
+//! Module routes are boxes, local views are ellipses, guards are diamonds and
+//! their redirect views are notes.
+
+use crate::docs::tree::{RenderedBy, RouteNode};
+
+const HEADER: &str = r###"digraph routes {
+    rankdir=LR;
+    node [fontname="Helvetica"];
+    edge [fontname="Helvetica"];
+"###;
+
+pub fn write_route_graph(routes: &[RouteNode],) -> String {
+    let mut graph = DotGraph {
+        content: HEADER.to_string(),
+        nodes: vec![],
+    };
+    graph.node("Routes", "Routes", "component",);
+    graph.routes("Routes", routes,);
+    format!("{}}}\n", graph.content)
+}
+
+struct DotGraph {
+    content: String,
+    /// Ids already written so guards and redirects shared by routes appear once.
+    nodes: Vec<String,>,
+}
+
+impl DotGraph {
+    /// Write the node if new and tell if it has been written.
+    fn node(&mut self, id: &str, label: &str, shape: &str,) -> bool {
+        if self.nodes.iter().any(|n| n == id,) {
+            return false;
+        }
+        self.nodes.push(id.to_string(),);
+        self.content = format!(
+            "{}    \"{}\" [label=\"{}\", shape={}];\n",
+            self.content,
+            escape(id,),
+            escape(label,),
+            shape
+        );
+        true
+    }
+
+    fn edge(&mut self, from: &str, to: &str, label: Option<&str,>,) {
+        let attributes = match label {
+            Some(label,) => format!(" [label=\"{}\", style=dashed]", escape(label,)),
+            None => "".to_string(),
+        };
+        self.content = format!(
+            "{}    \"{}\" -> \"{}\"{};\n",
+            self.content,
+            escape(from,),
+            escape(to,),
+            attributes
+        );
+    }
+
+    /// Write the variants of a `Routes` enum and follow the nested ones.
+    fn routes(&mut self, scope: &str, routes: &[RouteNode],) {
+        for node in routes {
+            let id = format!("{}::{}", scope, node.route.name);
+            let label = format!("{}\n{}", node.route.name, node.path);
+            match &node.rendered_by {
+                RenderedBy::Module(_,) => self.node(&id, &label, "box",),
+                RenderedBy::LocalView(view,) => {
+                    self.node(&id, &format!("{}\nfn {}", label, view), "ellipse",)
+                },
+            };
+            self.edge(scope, &id, None,);
+
+            if let Some(guard,) = &node.guard {
+                let guard_id = format!("{}::guard::{}", scope, guard);
+                let new_guard = self.node(&guard_id, &format!("fn {}", guard), "diamond",);
+                self.edge(&id, &guard_id, Some("guard",),);
+                if let (true, Some(redirect,),) = (new_guard, &node.redirect,) {
+                    let redirect_id = format!("{}::view::{}", scope, redirect);
+                    self.node(&redirect_id, &format!("fn {}", redirect), "note",);
+                    self.edge(&guard_id, &redirect_id, Some("redirect",),);
+                }
+            }
+
+            if !node.children.is_empty() {
+                let nested_id = format!("{}::Routes", id);
+                self.node(&nested_id, &format!("{}::Routes", node.route.name), "component",);
+                self.edge(&id, &nested_id, None,);
+                self.routes(&nested_id, &node.children,);
+            }
+        }
+    }
+}
+
+fn escape(value: &str,) -> String {
+    value.replace('"', "\\\"",).replace('\n', "\\n",)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        constants::_FILE_WITH_ROUTES_AND_MODEL,
+        content::SeedContent,
+        docs::{dot::write_route_graph, tree::get_route_tree},
+        find_model, find_routes,
+    };
+
+    #[test]
+    fn test_write_route_graph() {
+        let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+        let model = find_model(&parsed_file,);
+        let routes_enum = find_routes(&parsed_file,);
+        let content = SeedContent::new(routes_enum.unwrap(), model.unwrap(), "", "",);
+
+        let result = write_route_graph(&get_route_tree(&content,),);
+
+        assert!(result.starts_with("digraph routes {\n"));
+        assert!(result.ends_with("}\n"));
+        assert!(result.contains(
+            "    \"Routes::Admin\" [label=\"Admin\\n/admin/:id/*\", shape=box];\n"
+        ));
+        assert!(result.contains(
+            "    \"Routes::Home\" [label=\"Home\\n/\\nfn home\", shape=ellipse];\n"
+        ));
+        assert!(result.contains(
+            "    \"Routes::Settings\" -> \"Routes::guard::guard\" [label=\"guard\", style=dashed];\n"
+        ));
+        assert!(result.contains(
+            "    \"Routes::guard::admin_guard\" -> \"Routes::view::forbidden_user\" [label=\"redirect\", \
+             style=dashed];\n"
+        ));
+        assert_eq!(
+            result
+                .matches("\"Routes::guard::guard\" [label=\"fn guard\", shape=diamond]")
+                .count(),
+            1
+        );
+        assert_eq!(
+            result
+                .matches("\"Routes::guard::guard\" -> \"Routes::view::forbidden\"")
+                .count(),
+            1
+        );
+    }
+}
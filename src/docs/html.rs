@@ -0,0 +1,49 @@
+//! Route map as a standalone HTML page.
+
+use crate::docs::{get_rows, tree::RouteNode};
+
+const HEADER: &str = r###"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8">
+    <title>Route map</title>
+</head>
+<body>
+<h1>Route map</h1>
+<table>
+    <thead>
+        <tr><th>Path</th><th>Route</th><th>Parameters</th><th>Guard</th><th>Redirect</th><th>Rendered by</th></tr>
+    </thead>
+    <tbody>
+"###;
+
+const FOOTER: &str = r###"    </tbody>
+</table>
+</body>
+</html>
+"###;
+
+pub fn write_route_map(routes: &[RouteNode],) -> String {
+    let mut content = HEADER.to_string();
+    for row in get_rows(routes,) {
+        content = format!(
+            "{}        <tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            content,
+            escape(&row.path,),
+            escape(&row.route,),
+            escape(&row.parameters,),
+            escape(&row.guard,),
+            escape(&row.redirect,),
+            escape(&row.rendered_by,),
+        );
+    }
+    format!("{}{}", content, FOOTER)
+}
+
+fn escape(value: &str,) -> String {
+    value
+        .replace('&', "&amp;",)
+        .replace('<', "&lt;",)
+        .replace('>', "&gt;",)
+        .replace('"', "&quot;",)
+}
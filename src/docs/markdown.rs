@@ -0,0 +1,66 @@
+//! Route map as a Markdown table.
+
+use crate::docs::{get_rows, tree::RouteNode};
+
+const HEADER: &str = r###"# Route map
+
+| Path | Route | Parameters | Guard | Redirect | Rendered by |
+| --- | --- | --- | --- | --- | --- |
+"###;
+
+pub fn write_route_map(routes: &[RouteNode],) -> String {
+    let mut content = HEADER.to_string();
+    for row in get_rows(routes,) {
+        content = format!(
+            "{}| {} | {} | {} | {} | {} | {} |\n",
+            content,
+            code(&row.path,),
+            code(&row.route,),
+            row.parameters,
+            code(&row.guard,),
+            code(&row.redirect,),
+            code(&row.rendered_by,),
+        );
+    }
+    content
+}
+
+fn code(value: &str,) -> String {
+    if value.is_empty() {
+        "".to_string()
+    } else {
+        format!("`{}`", value.replace('|', "\\|"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        constants::_FILE_WITH_ROUTES_AND_MODEL,
+        content::SeedContent,
+        docs::{markdown::write_route_map, tree::get_route_tree},
+        find_model, find_routes,
+    };
+
+    #[test]
+    fn test_write_route_map() {
+        let parsed_file = syn::parse_file(_FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+        let model = find_model(&parsed_file,);
+        let routes_enum = find_routes(&parsed_file,);
+        let content = SeedContent::new(
+            routes_enum.unwrap(),
+            model.unwrap(),
+            "./my_app/src",
+            "./my_app/src/lib.rs",
+        );
+
+        let result = write_route_map(&get_route_tree(&content,),);
+
+        assert!(result.starts_with("# Route map\n"));
+        assert!(result.contains(
+            "| `/admin/:id/*` | `Admin` | id, children | `admin_guard` | `forbidden_user` | \
+             `./my_app/src/pages/admin.rs` |\n"
+        ));
+        assert!(result.contains("| `/` | `Home` |  |  |  | `fn home` |\n"));
+    }
+}
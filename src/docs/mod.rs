@@ -0,0 +1,91 @@
+//! Write documentation about the routes of the app, such as the route map for
+//! product and QA teams or the route graph for architecture reviews.
+
+use crate::docs::tree::{RenderedBy, RouteNode};
+use std::str::FromStr;
+
+pub mod dot;
+pub mod html;
+pub mod markdown;
+pub mod tree;
+
+/// Format of the route map.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DocsFormat {
+    Markdown,
+    Html,
+}
+
+impl FromStr for DocsFormat {
+    type Err = String;
+
+    fn from_str(s: &str,) -> Result<Self, Self::Err,> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(DocsFormat::Markdown,),
+            "html" => Ok(DocsFormat::Html,),
+            _ => Err(format!("unknown format {}, expected markdown or html", s),),
+        }
+    }
+}
+
+/// Write the route map in the given format.
+pub fn write_route_map(routes: &[RouteNode], format: DocsFormat,) -> String {
+    match format {
+        DocsFormat::Markdown => markdown::write_route_map(routes,),
+        DocsFormat::Html => html::write_route_map(routes,),
+    }
+}
+
+/// One line of the route map, nested routes are flattened after their parent.
+pub(crate) struct RouteRow {
+    pub path: String,
+    /// Route name with its parents, ex: `Admin > Settings`.
+    pub route: String,
+    pub parameters: String,
+    pub guard: String,
+    pub redirect: String,
+    pub rendered_by: String,
+}
+
+pub(crate) fn get_rows(routes: &[RouteNode],) -> Vec<RouteRow,> {
+    let mut rows: Vec<RouteRow,> = Vec::new();
+    push_rows(routes, "", &mut rows,);
+    rows
+}
+
+fn push_rows(routes: &[RouteNode], parent: &str, rows: &mut Vec<RouteRow,>,) {
+    for node in routes {
+        let route = if parent.is_empty() {
+            node.route.name.clone()
+        } else {
+            format!("{} > {}", parent, node.route.name)
+        };
+
+        let mut parameters: Vec<&str,> = Vec::new();
+        if node.route.id_param {
+            parameters.push("id",);
+        }
+        if node.route.query {
+            parameters.push("query",);
+        }
+        if node.route.children {
+            parameters.push("children",);
+        }
+        if node.route.nested {
+            parameters.push("nested",);
+        }
+
+        rows.push(RouteRow {
+            path: node.path.clone(),
+            route: route.clone(),
+            parameters: parameters.join(", ",),
+            guard: node.guard.clone().unwrap_or_default(),
+            redirect: node.redirect.clone().unwrap_or_default(),
+            rendered_by: match &node.rendered_by {
+                RenderedBy::Module(path,) => path.clone(),
+                RenderedBy::LocalView(view,) => format!("fn {}", view),
+            },
+        },);
+        push_rows(&node.children, &route, rows,);
+    }
+}
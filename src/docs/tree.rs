@@ -0,0 +1,182 @@
+//! Build the tree of routes with what renders them and what protects them,
+//! following nested `Routes` enums in the module files when they exist.
+
+use crate::{
+    content::{SeedContent, SeedRoute},
+    parser::{find_model, find_routes},
+    path::parent_dir,
+};
+use std::{fs, path::Path};
+use syn::{ItemEnum, ItemStruct};
+
+/// What is displayed when the route is loaded.
+#[derive(Debug, PartialEq, Clone)]
+pub enum RenderedBy {
+    /// A module file with its own TEA content, ex: `./src/pages/login.rs`.
+    Module(String,),
+    /// A local view function on the target file.
+    LocalView(String,),
+}
+
+/// A route of the app with everything needed to document it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RouteNode {
+    pub route: SeedRoute,
+    /// The full url including the parents, ex: `/admin/:id/settings`.
+    pub path: String,
+    /// The guard function protecting the route.
+    pub guard: Option<String,>,
+    /// The view displayed when the guard refuses the access.
+    pub redirect: Option<String,>,
+    pub rendered_by: RenderedBy,
+    /// Routes from the nested `Routes` enum if its module file exists.
+    pub children: Vec<RouteNode,>,
+}
+
+/// Get the route tree from the content of the target file.
+pub fn get_route_tree(content: &SeedContent,) -> Vec<RouteNode,> {
+    get_route_nodes(content, "",)
+}
+
+fn get_route_nodes(content: &SeedContent, prefix: &str,) -> Vec<RouteNode,> {
+    let mut nodes: Vec<RouteNode,> = Vec::new();
+    for route in content.routes().values() {
+        let guard = content
+            .guards()
+            .values()
+            .find(|g| g.routes.iter().any(|r| r.name == route.name,),);
+
+        let module = content.modules().values().find(|m| {
+            m.origin_route()
+                .as_ref()
+                .is_some_and(|r| r.name == route.name,)
+        },);
+
+        // Every variant without #[view] is a module.
+        let rendered_by = match content
+            .local_views()
+            .values()
+            .find(|v| v.route.name == route.name,)
+        {
+            Some(view,) => RenderedBy::LocalView(view.name.clone(),),
+            None => RenderedBy::Module(
+                module
+                    .map(|m| m.meta().filepath().display().to_string(),)
+                    .unwrap_or_default(),
+            ),
+        };
+
+        let path = join_path(prefix, route,);
+        let children = match module {
+            Some(m,) if route.nested || route.children => load_content(m.meta().filepath(),)
+                .map(|nested| get_route_nodes(&nested, &children_prefix(&path,),),)
+                .unwrap_or_default(),
+            _ => vec![],
+        };
+
+        nodes.push(RouteNode {
+            route: route.clone(),
+            path,
+            guard: guard.map(|g| g.name.clone(),),
+            redirect: guard.map(|g| g.redirect.name.clone(),),
+            rendered_by,
+            children,
+        },);
+    }
+    nodes
+}
+
+/// Read a module file and extract its content if it has a `Routes` enum.
+pub fn load_content(file_path: &Path,) -> Option<SeedContent,> {
+    let (routes, model,) = load_routes(file_path,)?;
+    Some(SeedContent::new(routes, model, parent_dir(file_path,), file_path,),)
+}
+
+/// Read the `Routes` enum and the Model of a module file.
+/// The generated modules have an empty Model so we accept a file without one.
+pub fn load_routes(file_path: &Path,) -> Option<(ItemEnum, ItemStruct,),> {
+    let src = fs::read_to_string(file_path,).ok()?;
+    let parsed_file = syn::parse_file(&src,).ok()?;
+    let routes = find_routes(&parsed_file,)?;
+    let model = find_model(&parsed_file,)
+        .unwrap_or_else(|| syn::parse_str::<ItemStruct,>("struct Model {}",).unwrap(),);
+    Some((routes, model,),)
+}
+
+/// Prefix the url of the route with the one from its parent.
+fn join_path(prefix: &str, route: &SeedRoute,) -> String {
+    let url = route.url_path();
+    if prefix.is_empty() {
+        url
+    } else if url == "/" {
+        prefix.to_string()
+    } else if let Some(query,) = url.strip_prefix("/?",) {
+        format!("{}?{}", prefix, query)
+    } else {
+        format!("{}{}", prefix, url)
+    }
+}
+
+/// The part of the url the nested routes are appended to.
+fn children_prefix(path: &str,) -> String {
+    path.trim_end_matches("?query",)
+        .trim_end_matches("/*",)
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        constants::_FILE_WITH_ROUTES_AND_MODEL,
+        docs::tree::{get_route_tree, load_content, RenderedBy},
+        test_dir::TestDir,
+    };
+    use std::fs;
+
+    const DASHBOARD_MODULE: &str = r###"
+#[derive(Debug, PartialEq, Clone, RoutingModules)]
+pub enum Routes {
+    Message,
+    Tasks {
+        query: IndexMap<String, String>,
+    },
+    #[default_route]
+    #[as_path = ""]
+    #[view = " => root"]
+    Root,
+}
+"###;
+
+    #[test]
+    fn test_get_route_tree_with_nested_routes() {
+        let dir = TestDir::new("route_tree",);
+        fs::create_dir_all(dir.join("pages",),).unwrap();
+        fs::write(dir.join("lib.rs",), _FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+        fs::write(dir.join("pages/dashboard.rs",), DASHBOARD_MODULE,).unwrap();
+
+        let content = load_content(&dir.join("lib.rs",),).unwrap();
+        let tree = get_route_tree(&content,);
+
+        let login = tree.iter().find(|n| n.route.name == "Login",).unwrap();
+        assert_eq!(login.path, "/login?query");
+        assert_eq!(
+            login.rendered_by,
+            RenderedBy::Module(dir.join("pages/login.rs").to_str().unwrap().to_string())
+        );
+        assert!(login.children.is_empty());
+
+        let admin = tree.iter().find(|n| n.route.name == "Admin",).unwrap();
+        assert_eq!(admin.guard, Some("admin_guard".to_string()));
+        assert_eq!(admin.redirect, Some("forbidden_user".to_string()));
+
+        let home = tree.iter().find(|n| n.route.name == "Home",).unwrap();
+        assert_eq!(home.rendered_by, RenderedBy::LocalView("home".to_string()));
+
+        let dashboard = tree.iter().find(|n| n.route.name == "Dashboard",).unwrap();
+        let paths: Vec<&str,> = dashboard.children.iter().map(|n| n.path.as_str(),).collect();
+        assert_eq!(
+            paths,
+            vec!["/dashboard/message", "/dashboard/tasks?query", "/dashboard"]
+        );
+    }
+}
@@ -0,0 +1,922 @@
+//! Entry point to run the generation from a target file without the cli.
+
+use crate::{
+    content::{
+        app::get_app_files, component::get_component, form::get_form, guard::GuardReturn,
+        module::import::ImportModule, nav::get_nav, routing_test::get_routes_tests,
+        scope::CrateIndex, visibility::Visibilities, ContentSettings, RouteIssue, SeedContent,
+        SeedRoute,
+    },
+    docs::tree::{load_content, load_routes},
+    manifest::find_seed_packages,
+    parser::{find_model, find_routes},
+    path::{normalize, parent_dir},
+    writer::{checker::Checker, form::add_form, manager::ContentManager, ModulesWriter},
+};
+use anyhow::Context;
+use convert_case::{Case, Casing};
+use indexmap::map::IndexMap;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+use syn::{ItemEnum, ItemStruct};
+
+/// What to generate and from which file.
+#[derive(Debug, Clone)]
+pub struct GenerateOptions {
+    /// The file with the Routes enum and the Model, ex: `./src/lib.rs`.
+    pub path: PathBuf,
+    /// Generate a `routes_test` module checking the url of every route.
+    pub with_tests: bool,
+    /// `pub`, `pub(crate)` or private for each kind of generated item.
+    pub visibilities: Visibilities,
+    /// The type returned by the generated guards.
+    pub guard_return: GuardReturn,
+    /// Warnings such as a scope missing on the Model stop the generation.
+    pub strict: bool,
+    /// Generate a `nav` view with a link to every route without payload.
+    pub with_nav: bool,
+}
+
+impl GenerateOptions {
+    pub fn new(path: impl Into<PathBuf,>,) -> Self {
+        GenerateOptions {
+            path: path.into(),
+            with_tests: false,
+            visibilities: Visibilities::default(),
+            guard_return: GuardReturn::default(),
+            strict: false,
+            with_nav: false,
+        }
+    }
+
+    pub fn with_tests(mut self, with_tests: bool,) -> Self {
+        self.with_tests = with_tests;
+        self
+    }
+
+    pub fn with_visibilities(mut self, visibilities: Visibilities,) -> Self {
+        self.visibilities = visibilities;
+        self
+    }
+
+    pub fn with_guard_return(mut self, guard_return: GuardReturn,) -> Self {
+        self.guard_return = guard_return;
+        self
+    }
+
+    pub fn with_strict(mut self, strict: bool,) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn with_nav(mut self, with_nav: bool,) -> Self {
+        self.with_nav = with_nav;
+        self
+    }
+}
+
+/// How the generation went.
+#[derive(Debug, PartialEq, Clone)]
+pub enum GenerateStatus {
+    /// The files have been written.
+    Generated,
+    /// No Model found on the target file, nothing has been written.
+    NoModel,
+    /// No Routes found on the target file, nothing has been written.
+    NoRoutes,
+    /// The Routes enum has errors in `Report::issues`, or warnings in strict
+    /// mode, nothing has been written.
+    InvalidRoutes,
+}
+
+/// Summary of the generation.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Report {
+    pub status: GenerateStatus,
+    /// Problems found on the Routes enum, errors stop the generation.
+    pub issues: Vec<RouteIssue,>,
+    pub local_views: usize,
+    pub guards: usize,
+    pub modules: usize,
+    pub file_created: u32,
+    pub file_updated: u32,
+    pub file_ignored: u32,
+}
+
+impl Report {
+    fn new(status: GenerateStatus,) -> Self {
+        Report {
+            status,
+            issues: vec![],
+            local_views: 0,
+            guards: 0,
+            modules: 0,
+            file_created: 0,
+            file_updated: 0,
+            file_ignored: 0,
+        }
+    }
+
+    /// Fail when the generation refused to write because of the issues, so
+    /// the run does not end as a success.
+    pub fn check(&self,) -> anyhow::Result<(),> {
+        if self.status == GenerateStatus::InvalidRoutes {
+            anyhow::bail!("{} issues found, nothing was generated", self.issues.len());
+        }
+        Ok((),)
+    }
+}
+
+/// Summary of the generation for every Seed crate of a workspace.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct WorkspaceReport {
+    /// The report of each package by name.
+    pub reports: Vec<(String, Report,),>,
+    pub file_created: u32,
+    pub file_updated: u32,
+    pub file_ignored: u32,
+}
+
+impl WorkspaceReport {
+    fn add(&mut self, package: String, report: Report,) {
+        self.file_created += report.file_created;
+        self.file_updated += report.file_updated;
+        self.file_ignored += report.file_ignored;
+        self.reports.push((package, report,),);
+    }
+}
+
+/// Summary of the form added to a module.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormReport {
+    /// The file of the module.
+    pub file: PathBuf,
+    /// The parts of the form written on the file.
+    pub added: Vec<String,>,
+    /// The parts already on the file.
+    pub ignored: Vec<String,>,
+}
+
+/// Summary of the component written next to the target file.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ComponentReport {
+    /// The file of the component.
+    pub file: PathBuf,
+    /// The file did not exist and has been written.
+    pub file_created: bool,
+    /// The `mod` line has been added to the target file.
+    pub mod_added: bool,
+}
+
+/// Read and parse the rust file at the given path.
+pub fn parse_file(path: &Path,) -> anyhow::Result<syn::File,> {
+    let src = fs::read_to_string(path,)
+        .with_context(|| format!("Unable to read file {}", path.display()),)?;
+    Ok(syn::parse_file(&src,)?,)
+}
+
+/// Read the content of the target file with the modules of its crate to
+/// resolve the scopes, fails if there is no Routes or Model.
+pub fn read_content(path: &Path,) -> anyhow::Result<SeedContent,> {
+    let parsed_file = parse_file(path,)?;
+    let (routes, model,) = match (find_routes(&parsed_file,), find_model(&parsed_file,),) {
+        (Some(routes,), Some(model,),) => (routes, model,),
+        (None, _,) => anyhow::bail!("No routes detected in {}", path.display()),
+        (_, None,) => anyhow::bail!("No Model detected in {}", path.display()),
+    };
+    let settings = ContentSettings {
+        index: index_crate(path,),
+        ..ContentSettings::default()
+    };
+    let seed_content =
+        SeedContent::new_with_settings(routes, model, parent_dir(path,), path, &settings,);
+    Ok(seed_content,)
+}
+
+/// Read the nested `Routes` enums from the module files that already exist.
+pub fn get_nested_routes(
+    seed_content: &SeedContent,
+) -> IndexMap<String, IndexMap<String, SeedRoute,>,> {
+    let mut nested_routes = IndexMap::new();
+    for module in seed_content.modules().values() {
+        if let Some(route,) = module.origin_route() {
+            if route.nested || route.children {
+                if let Some(nested,) = load_content(module.meta().filepath(),) {
+                    nested_routes.insert(route.name.clone(), nested.routes().clone(),);
+                }
+            }
+        }
+    }
+    nested_routes
+}
+
+/// Read the nested `Routes` enums with the Model of the module files that
+/// already exist.
+pub fn get_nested_enums(
+    seed_content: &SeedContent,
+) -> IndexMap<String, (ItemEnum, ItemStruct,),> {
+    let mut nested_enums = IndexMap::new();
+    for module in seed_content.modules().values() {
+        if let Some(route,) = module.origin_route() {
+            if route.nested || route.children {
+                if let Some(nested,) = load_routes(module.meta().filepath(),) {
+                    nested_enums.insert(route.name.clone(), nested,);
+                }
+            }
+        }
+    }
+    nested_enums
+}
+
+/// The spinner used by the binaries to log the generation.
+pub fn new_spinner() -> ProgressBar {
+    let pb = ProgressBar::new_spinner();
+    pb.enable_steady_tick(120,);
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .tick_strings(&[
+                "▹▹▹▹▹",
+                "▸▹▹▹▹",
+                "▹▸▹▹▹",
+                "▹▹▸▹▹",
+                "▹▹▹▸▹",
+                "▹▹▹▹▸",
+                "▪▪▪▪▪",
+            ],)
+            .template("{spinner:.blue} {msg}",),
+    );
+    pb
+}
+
+/// Index the modules declared with `mod` from the target file, which is
+/// considered as the crate root. The files that cannot be parsed are skipped.
+pub fn index_crate(target_file: &Path,) -> CrateIndex {
+    let mut index = CrateIndex::default();
+    index_module_file(&mut index, target_file, &parent_dir(target_file,), Vec::new(),);
+    index
+}
+
+/// `dir` is where the `mod` declarations of the file are looked for, as
+/// `dir/name.rs` or `dir/name/mod.rs`.
+fn index_module_file(index: &mut CrateIndex, file: &Path, dir: &Path, path: Vec<String,>,) {
+    let parsed_file = match fs::read_to_string(file,)
+        .ok()
+        .and_then(|src| syn::parse_file(&src,).ok(),)
+    {
+        Some(parsed_file,) => parsed_file,
+        None => return,
+    };
+    index.add_module(path.clone(), &parsed_file.items,);
+    for item in parsed_file.items.iter() {
+        if let syn::Item::Mod(syn::ItemMod {
+            ident,
+            content: None,
+            ..
+        },) = item
+        {
+            let mut child = path.clone();
+            child.push(ident.to_string(),);
+            let child_dir = dir.join(ident.to_string(),);
+            let flat_file = dir.join(format!("{}.rs", ident),);
+            let folder_file = child_dir.join("mod.rs",);
+            if flat_file.is_file() {
+                index_module_file(index, &flat_file, &child_dir, child,);
+            } else if folder_file.is_file() {
+                index_module_file(index, &folder_file, &child_dir, child,);
+            }
+        }
+    }
+}
+
+/// Add to the module written as `pages::login` a form over the struct `name`
+/// of the crate of the target file, with a message per field.
+pub fn generate_form(
+    path: &Path,
+    name: &str,
+    module: &str,
+    visibilities: Visibilities,
+) -> anyhow::Result<FormReport,> {
+    let path = &normalize(path,);
+    let settings = ContentSettings {
+        visibilities,
+        index: index_crate(path,),
+        ..ContentSettings::default()
+    };
+    let (module_path, item,) = settings
+        .index
+        .resolve_struct(&[], name.trim_start_matches("crate::",),)
+        .ok_or_else(|| anyhow::anyhow!("No struct {} found from {}", name, path.display()),)?;
+    let item_path = ["crate".to_string(),]
+        .iter()
+        .chain(module_path.iter(),)
+        .chain([item.ident.to_string(),].iter(),)
+        .cloned()
+        .collect::<Vec<String,>>()
+        .join("::",);
+
+    let module_dir = module
+        .trim_start_matches("crate::",)
+        .split("::",)
+        .fold(parent_dir(path,), |dir, segment| dir.join(segment,),);
+    let file = [module_dir.with_extension("rs",), module_dir.join("mod.rs",),]
+        .iter()
+        .find(|file| file.is_file(),)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("No file found for the module {}", module),)?;
+    let src = fs::read_to_string(&file,)?;
+    let model = find_model(&syn::parse_file(&src,)?,);
+
+    let form = get_form(item, &item_path, model.as_ref(), &settings,);
+    let update = add_form(&src, &form,);
+    if update.src != src {
+        fs::write(&file, &update.src,)?;
+    }
+    Ok(FormReport {
+        file,
+        added: update.added,
+        ignored: update.ignored,
+    },)
+}
+
+/// Write the component with the props, as name and type, in its own file next
+/// to the target file and declare its module there. An existing file is left
+/// untouched.
+pub fn generate_component(
+    path: &Path,
+    name: &str,
+    props: &[(String, String,)],
+    visibilities: Visibilities,
+) -> anyhow::Result<ComponentReport,> {
+    let path = &normalize(path,);
+    let component = get_component(name, props,);
+    let current_path = parent_dir(path,);
+    let file = current_path.join(format!("{}.rs", component.module),);
+    let file_created = !file.exists();
+    if file_created {
+        fs::write(&file, &component.content,)?;
+    }
+
+    let mut root_module = ImportModule::new_target_file_module(&current_path, path,)
+        .map_err(|e| anyhow::anyhow!(e),)?;
+    root_module.imports_names.push(component.module.clone(),);
+    root_module
+        .imports_content
+        .push(format!("{}mod {};", visibilities.modules.prefix(), component.module),);
+    let src = fs::read_to_string(path,)?;
+    syn::parse_file(&src,)?;
+    let mod_added = Checker::return_mod_if_exist(&src, root_module.clone(),).is_empty();
+    if mod_added {
+        let separator = if src.ends_with('\n',) || src.is_empty() { "" } else { "\n" };
+        fs::write(path, format!("{}{}{}", src, separator, root_module.imports_to_write()),)?;
+    }
+    Ok(ComponentReport {
+        file,
+        file_created,
+        mod_added,
+    },)
+}
+
+/// Create the directory `name` in `dir` with the files of a Seed app having
+/// the routes, the first one being the default. Return the path of its
+/// `lib.rs` to generate the pages from.
+pub fn create_app(dir: &Path, name: &str, routes: &[String],) -> anyhow::Result<PathBuf,> {
+    if routes.is_empty() {
+        anyhow::bail!("The app needs at least one route");
+    }
+    let is_ident = |name: &str| syn::parse_str::<syn::Ident,>(name,).is_ok();
+    let mut variants: Vec<(String, &String,),> = Vec::new();
+    for route in routes {
+        let variant = route.to_case(Case::Pascal,);
+        if !is_ident(route,) || !is_ident(&variant,) || !is_ident(&variant.to_case(Case::Snake,),) {
+            anyhow::bail!("\"{}\" is not a valid name for a route", route);
+        }
+        if let Some((_, first,),) = variants.iter().find(|(found, _,)| *found == variant,) {
+            anyhow::bail!("\"{}\" and \"{}\" are both the route {}", first, route, variant);
+        }
+        variants.push((variant, route,),);
+    }
+    let mut components = Path::new(name,).components();
+    let single =
+        matches!((components.next(), components.next()), (Some(Component::Normal(_)), None));
+    if !single
+        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_',)
+        || !is_ident(&name.replace('-', "_",),)
+    {
+        anyhow::bail!("\"{}\" is not a valid name for a crate", name);
+    }
+    let app_dir = normalize(&dir.join(name,),);
+    if app_dir.exists() {
+        anyhow::bail!("{} already exists", app_dir.display());
+    }
+    fs::create_dir_all(app_dir.join("src",),)?;
+    for (file, content,) in get_app_files(name, routes,) {
+        fs::write(app_dir.join(file,), content,)?;
+    }
+    Ok(app_dir.join("src",).join("lib.rs",),)
+}
+
+/// Generate the modules, views and guards from the Routes enum of the file
+/// without logging anything.
+pub fn generate(options: &GenerateOptions,) -> anyhow::Result<Report,> {
+    generate_with_progress(options, ProgressBar::hidden(),)
+}
+
+/// Generate the modules, views and guards from the Routes enum of the file
+/// and log every step in the progress bar.
+pub fn generate_with_progress(
+    options: &GenerateOptions,
+    pb: ProgressBar,
+) -> anyhow::Result<Report,> {
+    let path = &normalize(&options.path,);
+    options.guard_return.check().map_err(|e| anyhow::anyhow!(e),)?;
+    let parsed_file = parse_file(path,)?;
+
+    pb.set_message("Searching for routes",);
+    let enum_route = find_routes(&parsed_file,);
+    let model = find_model(&parsed_file,);
+    let current_path = parent_dir(path,);
+    if model.is_none() {
+        pb.finish_with_message("No Model detected, so nothing will be created",);
+        Ok(Report::new(GenerateStatus::NoModel,),)
+    } else if let Some(routes,) = enum_route {
+        let settings = ContentSettings {
+            visibilities: options.visibilities,
+            guard_return: options.guard_return.clone(),
+            index: index_crate(path,),
+        };
+        let model = Option::unwrap(model,);
+        let mut seed_content = SeedContent::new_with_settings(
+            routes.clone(),
+            model.clone(),
+            &current_path,
+            path,
+            &settings,
+        );
+
+        let mut report = Report::new(GenerateStatus::Generated,);
+        report.issues = seed_content.route_issues();
+        report.local_views = seed_content.local_views().len();
+        report.guards = seed_content.guards().len();
+        report.modules = seed_content.modules().len();
+
+        let is_error = |issue: &RouteIssue| options.strict || issue.is_error();
+        for issue in report.issues.iter() {
+            if is_error(issue,) {
+                pb.println(format!("[!] {}", issue).as_str(),);
+            } else {
+                pb.println(format!("[=>] {}", issue).as_str(),);
+            }
+        }
+        if report.issues.iter().any(is_error,) {
+            pb.finish_with_message("Routes enum has errors, so nothing will be created",);
+            report.status = GenerateStatus::InvalidRoutes;
+            return Ok(report,);
+        }
+
+        pb.println(format!("-> found {} routes", &seed_content.routes().len()).as_str(),);
+
+        let nested_enums = get_nested_enums(&seed_content,);
+        seed_content.set_nested_routes(&nested_enums, &settings,);
+
+        if options.with_tests {
+            let nested_routes = get_nested_routes(&seed_content,);
+            let tests = get_routes_tests(&routes, seed_content.routes(), &nested_routes,);
+            seed_content.set_routes_tests(tests,);
+        }
+        if options.with_nav {
+            seed_content.set_nav(get_nav(&routes, &model, &settings,),);
+        }
+
+        pb.println(format!("-> found {} locals view to create", report.local_views).as_str(),);
+
+        pb.println(format!("-> found {} guards to create", report.guards).as_str(),);
+        pb.println(format!("-> found {} modules to create", report.modules).as_str(),);
+
+        pb.println("[+] finished parsing the file",);
+
+        pb.set_message(format!("creating local views on {}", path.display()).as_str(),);
+
+        pb.set_message("Updating your files.",);
+
+        let writer = ModulesWriter::new(seed_content, pb, current_path, path.to_path_buf(),);
+
+        let mut content_manager = ContentManager::new(writer,);
+        content_manager
+            .add_or_update_imports()
+            .add_or_update_content()
+            .add_or_update_dispatch_arms()
+            .add_or_update_local_content()
+            .add_or_update_nav()
+            .add_or_update_routes_tests();
+
+        content_manager
+            .writer
+            .log_info(format!("Created {} new files", content_manager.file_created).as_str(),);
+
+        content_manager
+            .writer
+            .log_info(format!("Updated {} files", content_manager.file_updated).as_str(),);
+
+        content_manager
+            .writer
+            .log_info(format!("Ignored {} files", content_manager.file_ignored).as_str(),);
+
+        content_manager.writer.pb.finish_with_message("Done",);
+
+        report.file_created = content_manager.file_created;
+        report.file_updated = content_manager.file_updated;
+        report.file_ignored = content_manager.file_ignored;
+        Ok(report,)
+    } else {
+        pb.finish_with_message("No routes detected, so nothing will be created",);
+        Ok(Report::new(GenerateStatus::NoRoutes,),)
+    }
+}
+
+/// Generate for every member of the workspace depending on `seed_routing`
+/// without logging anything.
+/// `options.path` is replaced by the entry point of each member.
+pub fn generate_workspace(
+    manifest_path: &Path,
+    options: &GenerateOptions,
+) -> anyhow::Result<WorkspaceReport,> {
+    generate_workspace_with_progress(manifest_path, options, ProgressBar::hidden,)
+}
+
+/// Generate for every member of the workspace depending on `seed_routing`,
+/// `new_pb` gives the progress bar logging the generation of each crate.
+/// `options.path` is replaced by the entry point of each member.
+pub fn generate_workspace_with_progress(
+    manifest_path: &Path,
+    options: &GenerateOptions,
+    new_pb: impl Fn() -> ProgressBar,
+) -> anyhow::Result<WorkspaceReport,> {
+    let mut workspace_report = WorkspaceReport::default();
+    for package in find_seed_packages(manifest_path,)? {
+        let options = GenerateOptions {
+            path: package.entry_point.clone(),
+            ..options.clone()
+        };
+        let report = generate_with_progress(&options, new_pb(),).map_err(|e| {
+            anyhow::anyhow!("generation failed for package {} : {}", package.name, e)
+        },)?;
+        workspace_report.add(package.name, report,);
+    }
+    Ok(workspace_report,)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        constants::{_FILE_WITHOUT_ROUTES_NOR_MODEL, _FILE_WITH_ROUTES_AND_MODEL},
+        content::{visibility::Visibilities, RouteIssue},
+        generation::{
+            create_app, generate, generate_component, generate_workspace, index_crate,
+            parse_file, read_content, GenerateOptions, GenerateStatus,
+        },
+        parser::update::find_update_match,
+        test_dir::TestDir,
+    };
+    use std::fs;
+
+    #[test]
+    fn test_generate() {
+        let dir = TestDir::new("generate",);
+        fs::write(dir.join("lib.rs",), _FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+
+        let report = generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+
+        assert_eq!(report.status, GenerateStatus::Generated);
+        assert!(report.issues.is_empty());
+        assert_eq!(report.modules, 4);
+        assert_eq!(report.guards, 2);
+        assert_eq!(report.local_views, 3);
+        assert!(dir.join("pages/mod.rs").exists());
+        assert!(dir.join("pages/settings.rs").exists());
+
+        let report = generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+        assert_eq!(report.file_created, 0);
+
+        fs::write(dir.join("lib.rs",), _FILE_WITHOUT_ROUTES_NOR_MODEL,).unwrap();
+        let report = generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+        assert_eq!(report.status, GenerateStatus::NoModel);
+    }
+
+    #[test]
+    fn test_parse_missing_file() {
+        let dir = TestDir::new("missing_file",);
+        let path = dir.join("lib.rs",);
+
+        let error = parse_file(&path,).err().unwrap();
+        assert_eq!(error.to_string(), format!("Unable to read file {}", path.display()));
+        assert!(read_content(&path,).is_err());
+        assert!(generate(&GenerateOptions::new(&path,),).is_err());
+
+        fs::write(&path, "fn broken(",).unwrap();
+        assert!(parse_file(&path,).is_err());
+    }
+
+    #[test]
+    fn test_generate_strict() {
+        let dir = TestDir::new("generate_strict",);
+        fs::write(
+            dir.join("lib.rs",),
+            _FILE_WITH_ROUTES_AND_MODEL.replace("theme => home", "them => home",),
+        )
+        .unwrap();
+
+        let options = GenerateOptions::new(dir.join("lib.rs",),).with_strict(true,);
+        let report = generate(&options,).unwrap();
+        assert_eq!(report.status, GenerateStatus::InvalidRoutes);
+        assert_eq!(report.issues.len(), 1);
+        assert!(!dir.join("pages").exists());
+
+        assert!(report.check().is_err());
+
+        let report = generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+        assert_eq!(report.status, GenerateStatus::Generated);
+        assert_eq!(report.issues.len(), 1);
+        assert!(report.check().is_ok());
+    }
+
+    #[test]
+    fn test_generate_refused_without_strict() {
+        let dir = TestDir::new("generate_refused",);
+        fs::write(
+            dir.join("lib.rs",),
+            _FILE_WITH_ROUTES_AND_MODEL.replace("    Settings,", "    #[default_route]\n    Settings,",),
+        )
+        .unwrap();
+
+        let report = generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+        assert!(matches!(report.issues[0], RouteIssue::MultipleDefaultRoutes(_)));
+        assert_eq!(report.status, GenerateStatus::InvalidRoutes);
+        assert!(report.check().is_err());
+        assert!(!dir.join("pages").exists());
+    }
+
+    #[test]
+    fn test_generate_refused_on_guard_conflict() {
+        let dir = TestDir::new("generate_guard_conflict",);
+        fs::write(
+            dir.join("lib.rs",),
+            _FILE_WITH_ROUTES_AND_MODEL.replace(
+                "logged_user => admin_guard => forbidden_user",
+                "logged_user => guard => forbidden_user",
+            ),
+        )
+        .unwrap();
+
+        let report = generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+        assert!(matches!(report.issues[0], RouteIssue::GuardConflict { .. }));
+        assert_eq!(report.status, GenerateStatus::InvalidRoutes);
+        assert!(report.check().is_err());
+        assert!(!dir.join("pages").exists());
+    }
+
+    #[test]
+    fn test_generate_dispatch_arms() {
+        let dir = TestDir::new("generate_dispatch",);
+        let update = "\nfn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {\n    \
+                      match msg {\n        Msg::Login(m) => pages::login::update(m, &mut \
+                      model.login, &mut orders.proxy(Msg::Login))\n    }\n}\n";
+        fs::write(
+            dir.join("lib.rs",),
+            format!("{}{}", _FILE_WITH_ROUTES_AND_MODEL, update),
+        )
+        .unwrap();
+
+        generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+        let src = fs::read_to_string(dir.join("lib.rs",),).unwrap();
+        let update_match = find_update_match(&syn::parse_file(&src,).unwrap(),).unwrap();
+        assert_eq!(update_match.variants, vec!["Login", "Settings", "Dashboard", "Admin"]);
+        assert!(src.contains(
+            "        Msg::Settings(settings_msg,) => pages::settings::update(\n            \
+             settings_msg,\n            &mut model.settings,\n            &mut \
+             orders.proxy(Msg::Settings,),\n        ),\n"
+        ));
+
+        let report = generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+        assert_eq!(report.file_updated, 0);
+        assert_eq!(fs::read_to_string(dir.join("lib.rs",),).unwrap(), src);
+    }
+
+    #[test]
+    fn test_generate_nested_dispatch() {
+        let dir = TestDir::new("generate_nested",);
+        fs::write(dir.join("lib.rs",), _FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+        let dashboard = dir.join("pages/dashboard.rs",);
+
+        generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+        let src = fs::read_to_string(&dashboard,).unwrap();
+        assert!(!src.contains("match nested"));
+        fs::write(
+            &dashboard,
+            src.replace(
+                " pub enum Routes {\n\n }",
+                " pub enum Routes {\n    Message,\n    #[default_route]\n    #[view = \" => \
+                 root\"]\n    Root,\n }",
+            ),
+        )
+        .unwrap();
+
+        generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+        let src = fs::read_to_string(&dashboard,).unwrap();
+        assert!(syn::parse_file(&src,).is_ok());
+        assert!(src.contains(
+            "        Routes::Message => message::view(&model.message,).map_msg(Msg::Message,),\n"
+        ));
+        assert!(src.contains("        Routes::Root => root(model,),\n"));
+        assert!(src.contains(
+            "#[derive(Default)]\n pub struct Model {\n    pub message: message::Model,\n }"
+        ));
+        assert!(src.contains(
+            "               model.message = message::init(url, &mut previous_state.message, \
+             &mut orders.proxy(Msg::Message,),);\n"
+        ));
+        assert_eq!(src.matches("fn view(",).count(), 1);
+        assert_eq!(src.matches("fn init(",).count(), 1);
+        assert!(src.contains("        let node = view(&Routes::Message, &model);\n"));
+        assert_eq!(src.matches("#[cfg(test)]",).count(), 1);
+
+        generate(&GenerateOptions::new(dir.join("lib.rs",),),).unwrap();
+        assert_eq!(fs::read_to_string(&dashboard,).unwrap(), src);
+    }
+
+    #[test]
+    fn test_generate_nav() {
+        let dir = TestDir::new("generate_nav",);
+        fs::write(dir.join("lib.rs",), _FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+        let options = GenerateOptions::new(dir.join("lib.rs",),).with_nav(true,);
+
+        generate(&options,).unwrap();
+        let src = fs::read_to_string(dir.join("lib.rs",),).unwrap();
+        assert_eq!(src.matches("fn nav(",).count(), 1);
+        assert!(src.contains("Routes::Forbidden.to_url()"));
+
+        generate(&options,).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("lib.rs",),).unwrap(), src);
+
+        fs::write(
+            dir.join("lib.rs",),
+            src.replace("    Home,\n}", "    Home,\n    #[view = \" => about\"]\n    About,\n}",),
+        )
+        .unwrap();
+        generate(&options,).unwrap();
+        let src = fs::read_to_string(dir.join("lib.rs",),).unwrap();
+        assert_eq!(src.matches("fn nav(",).count(), 1);
+        assert!(src.contains("Routes::About.to_url()"));
+        assert!(syn::parse_file(&src,).is_ok());
+    }
+
+    #[test]
+    fn test_generate_component() {
+        let dir = TestDir::new("generate_component",);
+        fs::write(dir.join("lib.rs",), _FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+        let props = vec![("title".to_string(), "String".to_string(),)];
+
+        let report =
+            generate_component(&dir.join("lib.rs",), "Card", &props, Visibilities::default(),)
+                .unwrap();
+        assert!(report.file_created && report.mod_added);
+        assert!(fs::read_to_string(dir.join("card.rs",),).unwrap().contains("pub struct Card<"));
+        let lib = fs::read_to_string(dir.join("lib.rs",),).unwrap();
+        assert!(lib.ends_with("\nmod card;\n"));
+
+        let report =
+            generate_component(&dir.join("lib.rs",), "Card", &[], Visibilities::default(),)
+                .unwrap();
+        assert!(!report.file_created && !report.mod_added);
+        assert_eq!(fs::read_to_string(dir.join("lib.rs",),).unwrap(), lib);
+    }
+
+    #[test]
+    fn test_create_app() {
+        let dir = TestDir::new("create_app",);
+        let routes = vec!["Home".to_string(), "Login".to_string()];
+
+        let path = create_app(&dir, "my_app", &routes,).unwrap();
+        assert_eq!(path, dir.join("my_app/src/lib.rs"));
+        for file in ["Cargo.toml", "index.html", "Makefile.toml",].iter() {
+            assert!(dir.join("my_app",).join(file,).exists());
+        }
+        let report = generate(&GenerateOptions::new(&path,),).unwrap();
+        assert_eq!(report.status, GenerateStatus::Generated);
+        assert!(dir.join("my_app/src/pages/home.rs").exists());
+        assert!(dir.join("my_app/src/pages/login.rs").exists());
+        let lib = fs::read_to_string(&path,).unwrap();
+        assert!(lib.contains("Msg::Login(login_msg,) => pages::login::update("));
+        assert!(syn::parse_file(&lib,).is_ok());
+
+        assert!(create_app(&dir, "my_app", &routes,).is_err());
+        assert!(create_app(&dir, "other_app", &[],).is_err());
+        assert!(create_app(&dir, "other_app", &["Not Valid".to_string()],).is_err());
+        assert!(create_app(&dir, "other_app", &["type".to_string()],).is_err());
+        let error = create_app(&dir, "other_app", &["Home".to_string(), "home".to_string()],);
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "\"Home\" and \"home\" are both the route Home"
+        );
+        let routes = ["user_settings".to_string(), "UserSettings".to_string()];
+        assert!(create_app(&dir, "other_app", &routes,).is_err());
+        for name in ["../other_app", "other/app", "", "other app", "1app", "fn", "app\"",].iter() {
+            assert!(create_app(&dir, name, &["Home".to_string()],).is_err(), "{}", name);
+        }
+        assert!(!dir.parent().unwrap().join("other_app").exists());
+        assert!(!dir.join("other_app").exists());
+    }
+
+    #[test]
+    fn test_generate_with_unclean_path() {
+        let dir = TestDir::new("generate_unclean_path",);
+        fs::create_dir_all(dir.join("src",),).unwrap();
+        fs::write(dir.join("src/lib.rs",), _FILE_WITH_ROUTES_AND_MODEL,).unwrap();
+
+        let unclean = dir.join("src//./pages/../lib.rs",);
+        let report = generate(&GenerateOptions::new(unclean,),).unwrap();
+        assert_eq!(report.status, GenerateStatus::Generated);
+        assert!(dir.join("src/pages/mod.rs").exists());
+        assert!(dir.join("src/pages/login.rs").exists());
+
+        let report = generate(&GenerateOptions::new(dir.join("src/lib.rs",),),).unwrap();
+        assert_eq!(report.file_created, 0);
+        let lib = fs::read_to_string(dir.join("src/lib.rs",),).unwrap();
+        assert_eq!(lib.matches("mod pages;").count(), 1);
+    }
+
+    #[test]
+    fn test_generate_workspace() {
+        let dir = TestDir::new("generate_workspace",);
+        for member in ["front", "back_office", "server"].iter() {
+            fs::create_dir_all(dir.join(member,).join("src",),).unwrap();
+            fs::write(dir.join(member,).join("src/lib.rs",), _FILE_WITH_ROUTES_AND_MODEL,)
+                .unwrap();
+        }
+        let manifest = |name: &str, dependencies: &str| {
+            format!(
+                "[package]\nname = \"{}\"\nversion = \"0.1.0\"\n\n[dependencies]\n{}\n",
+                name, dependencies
+            )
+        };
+        fs::write(
+            dir.join("Cargo.toml",),
+            "[workspace]\nmembers = [\"front\", \"back_office\", \"server\"]\n",
+        )
+        .unwrap();
+        fs::write(dir.join("front/Cargo.toml",), manifest("front", "seed_routing = \"0.1\""),)
+            .unwrap();
+        fs::write(
+            dir.join("back_office/Cargo.toml",),
+            manifest("back_office", "seed_routing = \"0.1\""),
+        )
+        .unwrap();
+        fs::write(dir.join("server/Cargo.toml",), manifest("server", "anyhow = \"1\""),).unwrap();
+
+        let report =
+            generate_workspace(&dir.join("Cargo.toml",), &GenerateOptions::new("",),).unwrap();
+
+        let packages: Vec<&str,> = report.reports.iter().map(|(name, _,)| name.as_str(),).collect();
+        assert_eq!(packages, vec!["front", "back_office"]);
+        assert!(report.reports.iter().all(|(_, r,)| r.status == GenerateStatus::Generated));
+        assert_eq!(
+            report.file_created,
+            report.reports.iter().map(|(_, r,)| r.file_created).sum::<u32>()
+        );
+        assert!(report.file_created > 0);
+        assert!(dir.join("back_office/src/pages/mod.rs").exists());
+        assert!(!dir.join("server/src/pages").exists());
+    }
+
+    #[test]
+    fn test_index_crate() {
+        let dir = TestDir::new("index",);
+        fs::create_dir_all(dir.join("user",),).unwrap();
+        fs::write(
+            dir.join("lib.rs",),
+            "mod session;\nmod user;\nmod not_created;\nstruct Model { session: session::Session }",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("session.rs",),
+            "use crate::user::LoggedData;\npub struct Session { pub user: Option<LoggedData> }",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("user/mod.rs",),
+            "pub struct LoggedData { pub role: Role }\npub enum Role { Admin }",
+        )
+        .unwrap();
+
+        let index = index_crate(&dir.join("lib.rs",),);
+        let paths: Vec<String,> = index.modules.iter().map(|m| m.path.join("::",),).collect();
+        assert_eq!(paths, vec!["", "session", "user"]);
+        let model = &index.modules[0].structs[0];
+        assert_eq!(
+            index.resolve_scope(model, "session.user",).unwrap().ty,
+            "Option<crate::user::LoggedData>"
+        );
+    }
+}
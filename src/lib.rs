@@ -0,0 +1,41 @@
+//! # proto_seeder
+//!
+//! `proto_seeder` is a experimental cli that generate Seed code based on
+//! routing from [seed_routing](https://github.com/arn-the-long-beard/seed-routing) with RoutingModules.
+//!
+//! The generation can be driven from your own tooling as well :
+//!
+//! ```no_run
+//! use proto_seeder::{generate, GenerateOptions, GenerateStatus};
+//!
+//! let report = generate(&GenerateOptions::new("./src/lib.rs",).with_tests(true,),).unwrap();
+//! assert_eq!(report.status, GenerateStatus::Generated);
+//! println!("created {} files", report.file_created);
+//! ```
+
+// the tests compare booleans with `assert_eq!` so the value is printed
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
+
+#[rustfmt::skip]
+mod constants;
+
+pub mod build_script;
+pub mod content;
+pub mod docs;
+pub mod generation;
+pub mod manifest;
+pub mod parser;
+mod path;
+#[cfg(test)]
+mod test_dir;
+mod writer;
+
+pub use crate::{
+    content::{module::SeedModule, RouteIssue, SeedContent, SeedRoute},
+    generation::{
+        create_app, generate, generate_component, generate_form, generate_with_progress,
+        generate_workspace, generate_workspace_with_progress, new_spinner, read_content,
+        ComponentReport, FormReport, GenerateOptions, GenerateStatus, Report, WorkspaceReport,
+    },
+    parser::{find_function, find_message, find_mod, find_model, find_routes},
+};
@@ -3,158 +3,260 @@
 //! `proto_seeder` is a experimental cli that generate Seed code based on
 //! routing from [seed_routing](https://github.com/arn-the-long-beard/seed-routing) with RoutingModules.
 
-use crate::{
-    content::SeedContent,
-    parser::{find_model, find_routes},
-    writer::{manager::ContentManager, ModulesWriter},
+use proto_seeder::{
+    content::{guard::GuardReturn, visibility::Visibilities},
+    docs::{dot::write_route_graph, tree::get_route_tree, write_route_map, DocsFormat},
+    create_app, generate_component, generate_form, generate_with_progress, new_spinner,
+    parser::parse_named_fields, read_content, GenerateOptions,
 };
-use indicatif::{ProgressBar, ProgressStyle};
 use std::{
-    fs::{File},
-    io::Read,
+    fs,
+    path::{Path, PathBuf},
 };
 use structopt::StructOpt;
 
-#[rustfmt::skip]
-mod constants;
-
-mod content;
-mod parser;
-mod writer;
-
 /// Generate code from the Routes enum from the given file
 #[derive(StructOpt, Debug)]
 struct Cli {
     /// Will find every routes in the folder and generate views, guards and modules from.
     /// ## Not implemented ##
     ///
+    #[allow(dead_code)]
     #[structopt(short, long)]
     recursive: bool,
 
     /// Test debug mode
     /// For now this is dummy , we need to add log level later.
     /// ## Not implemented ##
+    #[allow(dead_code)]
     #[structopt(short, long)]
     debug: bool,
+
+    /// Generate a `routes_test` module checking the url of every route.
+    #[structopt(short, long)]
+    tests: bool,
+
+    /// Visibility by kind of item as `kind=pub|pub(crate)|private`, comma
+    /// separated. Kinds are mod, folder_mod, tea, guard and view.
+    #[structopt(long)]
+    visibility: Option<Visibilities,>,
+
+    /// The type returned by the guards, `Option<bool>` by default, `bool` or
+    /// a type of your project.
+    #[structopt(long)]
+    guard_return: Option<GuardReturn,>,
+
+    /// The expression returned by the guards of a custom `--guard-return`,
+    /// reading the `allowed` condition.
+    #[structopt(long)]
+    guard_ok: Option<String,>,
+
+    /// Fail on warnings, such as a guard or view scope missing on the Model.
+    #[structopt(long)]
+    strict: bool,
+
+    /// Generate a `nav` view with a link to every route without payload.
+    #[structopt(long)]
+    nav: bool,
     /// The path to the file to read.
     #[structopt(parse(from_os_str))]
-    path: std::path::PathBuf,
+    path: Option<PathBuf,>,
+
+    #[structopt(subcommand)]
+    command: Option<Command,>,
+}
+
+#[derive(StructOpt, Debug)]
+enum Command {
+    /// Write the route map with every url of the app as Markdown or HTML.
+    Docs {
+        /// The path to the file with the Routes enum.
+        #[structopt(parse(from_os_str))]
+        path: PathBuf,
+        /// markdown or html.
+        #[structopt(short, long, default_value = "markdown")]
+        format: DocsFormat,
+        /// Write in this file instead of the standard output.
+        #[structopt(short, long, parse(from_os_str))]
+        output: Option<PathBuf,>,
+    },
+    /// Print the route hierarchy as a Graphviz DOT graph.
+    Graph {
+        /// The path to the file with the Routes enum.
+        #[structopt(parse(from_os_str))]
+        path: PathBuf,
+    },
+    /// Add a form over a struct of the crate to a module, with a message and
+    /// an input per field.
+    Form {
+        /// The struct to edit, as `LoginCredentials` or `models::auth::LoginCredentials`.
+        name: String,
+        /// The module to write the form on, as `pages::login`.
+        #[structopt(short, long)]
+        module: String,
+        /// The crate root the struct and the module are found from.
+        #[structopt(short, long, parse(from_os_str), default_value = "./src/lib.rs")]
+        path: PathBuf,
+        /// Visibility by kind of item as `kind=pub|pub(crate)|private`, comma
+        /// separated. Kinds are tea and view.
+        #[structopt(long)]
+        visibility: Option<Visibilities,>,
+    },
+    /// Write a component built with a setter per prop, like `TopBar`, next to
+    /// the target file.
+    Component {
+        /// The name of the component, as `Card`.
+        name: String,
+        /// The props as `title: String, disabled: bool`.
+        #[structopt(long, default_value = "")]
+        props: String,
+        /// The file declaring the module of the component.
+        #[structopt(short, long, parse(from_os_str), default_value = "./src/lib.rs")]
+        path: PathBuf,
+        /// Visibility by kind of item as `kind=pub|pub(crate)|private`, comma
+        /// separated. Only mod is used.
+        #[structopt(long)]
+        visibility: Option<Visibilities,>,
+    },
+    /// Create a Seed app with seed_routing and generate the pages of its
+    /// routes.
+    New {
+        /// The name of the crate and of its directory.
+        name: String,
+        /// The routes as `Home,Login,Dashboard`, the first one is the default.
+        #[structopt(long, default_value = "Home", use_delimiter = true)]
+        routes: Vec<String,>,
+        /// The directory to create the app in.
+        #[structopt(short, long, parse(from_os_str), default_value = ".")]
+        dir: PathBuf,
+        /// Generate a `routes_test` module checking the url of every route.
+        #[structopt(short, long)]
+        tests: bool,
+        /// Generate a `nav` view with a link to every route without payload.
+        #[structopt(long)]
+        nav: bool,
+    },
 }
 
 fn main() -> anyhow::Result<(),> {
     let args: Cli = Cli::from_args();
 
-    if args.recursive {}
-
-    let pb = ProgressBar::new_spinner();
-    pb.enable_steady_tick(120,);
-    pb.set_style(
-        ProgressStyle::default_spinner()
-            .tick_strings(&[
-                "▹▹▹▹▹",
-                "▸▹▹▹▹",
-                "▹▸▹▹▹",
-                "▹▹▸▹▹",
-                "▹▹▹▸▹",
-                "▹▹▹▹▸",
-                "▪▪▪▪▪",
-            ],)
-            .template("{spinner:.blue} {msg}",),
-    );
-
-    let mut file = File::open(&args.path,)
-        .unwrap_or_else(|_| panic!("Unable to open file , {}", &args.path.to_str().unwrap()),);
-
-    let mut src = String::new();
-    file.read_to_string(&mut src,)
-        .expect("Unable to read file",);
-    let parsed_file = syn::parse_file(&src,)?;
-
-    pb.set_message("Searching for routes",);
-    let enum_route = find_routes(&parsed_file,);
-    let model = find_model(&parsed_file,);
-    let current_path = args
-        .path
-        .parent()
-        .unwrap()
-        .to_str()
-        .expect("should have gotten the current path",);
-    if model.is_none() {
-        pb.finish_with_message("No Model detected, so nothing will be created",);
-        return Ok((),);
-    } else if let Some(routes,) = enum_route {
-        let seed_content = SeedContent::new(
+    match (args.command, args.path,) {
+        (Some(Command::Docs {
+            path,
+            format,
+            output,
+        },), _,) => docs(&path, format, output,),
+        (Some(Command::Graph {
+            path,
+        },), _,) => graph(&path,),
+        (Some(Command::Form {
+            name,
+            module,
+            path,
+            visibility,
+        },), _,) => form(&path, &name, &module, visibility.unwrap_or_default(),),
+        (Some(Command::Component {
+            name,
+            props,
+            path,
+            visibility,
+        },), _,) => component(&path, &name, &props, visibility.unwrap_or_default(),),
+        (Some(Command::New {
+            name,
             routes,
-            Option::unwrap(model,),
-            current_path,
-            args.path
-                .to_str()
-                .expect("should get string of target file",),
-        );
-
-        pb.println(
-            format!(
-                "-> found {} locals view to create",
-                &seed_content.local_views().iter().len()
+            dir,
+            tests,
+            nav,
+        },), _,) => new(&dir, &name, &routes, tests, nav,),
+        (None, Some(path,),) => {
+            let guard_return = args
+                .guard_return
+                .unwrap_or_default()
+                .with_expression(args.guard_ok,)
+                .map_err(|e| anyhow::anyhow!(e),)?;
+            generate(
+                GenerateOptions::new(path,)
+                    .with_tests(args.tests,)
+                    .with_visibilities(args.visibility.unwrap_or_default(),)
+                    .with_guard_return(guard_return,)
+                    .with_strict(args.strict,)
+                    .with_nav(args.nav,),
             )
-            .as_str(),
-        );
+        },
+        (None, None,) => {
+            Cli::clap().print_help()?;
+            println!();
+            Ok((),)
+        },
+    }
+}
 
-        pb.println(
-            format!(
-                "-> found {} guards to create",
-                &seed_content.guards().iter().len()
-            )
-            .as_str(),
-        );
-        pb.println(
-            format!(
-                "-> found {} modules to create",
-                &seed_content.modules().iter().len()
-            )
-            .as_str(),
-        );
-
-        pb.println("[+] finished parsing the file",);
-
-        pb.set_message(
-            format!("creating local views on {}", &args.path.to_str().unwrap()).as_str(),
-        );
-
-        pb.set_message("Updating your files.",);
-
-        let  writer = ModulesWriter::new(
-            seed_content,
-            pb,
-            current_path.to_string(),
-            args.path
-                .to_str()
-                .expect("should get string of target file",)
-                .to_string(),
-        );
-
-        let mut content_manager = ContentManager::new(writer,);
-        content_manager
-            .add_or_update_imports()
-            .add_or_update_content()
-            .add_or_update_local_content();
-
-        content_manager
-            .writer
-            .log_info(format!("Created {} new files", content_manager.file_created).as_str(),);
-
-        content_manager
-            .writer
-            .log_info(format!("Updated {} files", content_manager.file_updated).as_str(),);
-
-        content_manager
-            .writer
-            .log_info(format!("Ignored {} files", content_manager.file_ignored).as_str(),);
-
-        content_manager.writer.pb.finish_with_message("Done",);
+/// Write the route map of the app for the given file.
+fn docs(path: &Path, format: DocsFormat, output: Option<PathBuf,>,) -> anyhow::Result<(),> {
+    let seed_content = read_content(path,)?;
+    let route_map = write_route_map(&get_route_tree(&seed_content,), format,);
+    match output {
+        Some(output,) => fs::write(output, route_map,)?,
+        None => print!("{}", route_map),
+    }
+    Ok((),)
+}
+
+/// Print the route graph of the app for the given file.
+fn graph(path: &Path,) -> anyhow::Result<(),> {
+    let seed_content = read_content(path,)?;
+    print!("{}", write_route_graph(&get_route_tree(&seed_content,),));
+    Ok((),)
+}
+
+/// Add the form over the struct to the module and log what was written.
+fn form(path: &Path, name: &str, module: &str, visibilities: Visibilities,) -> anyhow::Result<(),> {
+    let report = generate_form(path, name, module, visibilities,)?;
+    for added in report.added.iter() {
+        println!("[+] added {} to {}", added, report.file.display());
+    }
+    for ignored in report.ignored.iter() {
+        println!("[=>] {}", ignored);
+    }
+    Ok((),)
+}
+
+/// Write the component with its props and declare its module.
+fn component(
+    path: &Path,
+    name: &str,
+    props: &str,
+    visibilities: Visibilities,
+) -> anyhow::Result<(),> {
+    let props = parse_named_fields(props,).map_err(|_| {
+        anyhow::anyhow!("expected --props \"name: Type, ...\", found \"{}\"", props)
+    },)?;
+    let report = generate_component(path, name, &props, visibilities,)?;
+    if report.file_created {
+        println!("[+] created file at {}", report.file.display());
     } else {
-        pb.finish_with_message("No routes detected, so nothing will be created",);
-        return Ok((),);
+        println!("[=>] No need to create {} as it exists", report.file.display());
+    }
+    if report.mod_added {
+        println!("[+] added its mod to {}", path.display());
     }
     Ok((),)
 }
+
+/// Create the app then generate its pages.
+fn new(dir: &Path, name: &str, routes: &[String], tests: bool, nav: bool,) -> anyhow::Result<(),> {
+    let routes: Vec<String,> = routes.iter().map(|route| route.trim().to_string(),).collect();
+    let path = create_app(dir, name, &routes,)?;
+    println!("[+] created app at {}", dir.join(name,).display());
+    generate(
+        GenerateOptions::new(path,)
+            .with_tests(tests,)
+            .with_nav(nav,),
+    )
+}
+
+/// Generate the modules, views and guards from the Routes enum of the file.
+fn generate(options: GenerateOptions,) -> anyhow::Result<(),> {
+    generate_with_progress(&options, new_spinner(),)?.check()
+}
@@ -0,0 +1,248 @@
+//! Find the entry point of a crate from its `Cargo.toml` so the cli can run
+//! from anywhere inside the crate.
+
+use anyhow::{anyhow, bail};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use toml::Value;
+
+/// A package found in a manifest or in the members of a workspace.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Package {
+    pub name: String,
+    /// The `Cargo.toml` of the package.
+    pub manifest_path: PathBuf,
+    /// The source of the lib target, or of the first bin target when the
+    /// crate has no lib.
+    pub entry_point: PathBuf,
+    /// The crates in `[dependencies]`, with their real name when renamed.
+    pub dependencies: Vec<String,>,
+}
+
+impl Package {
+    /// Seed crates are the ones using `seed_routing`.
+    pub fn uses_seed_routing(&self,) -> bool {
+        self.dependencies.iter().any(|d| d == "seed_routing",)
+    }
+}
+
+/// Look for the closest `Cargo.toml` in the directory and its parents.
+pub fn find_manifest(dir: &Path,) -> Option<PathBuf,> {
+    dir.ancestors()
+        .map(|ancestor| ancestor.join("Cargo.toml",),)
+        .find(|manifest| manifest.is_file(),)
+}
+
+/// Read the packages of the manifest, the package itself and the members of
+/// the workspace when there is one.
+pub fn read_packages(manifest_path: &Path,) -> anyhow::Result<Vec<Package,>,> {
+    let manifest_path = manifest_path.canonicalize().map_err(|e| {
+        anyhow!("Unable to find manifest {} : {}", manifest_path.display(), e)
+    },)?;
+    let manifest = read_manifest(&manifest_path,)?;
+    let root = manifest_path.parent().expect("manifest should be in a directory",);
+
+    let mut packages = Vec::new();
+    if manifest.get("package",).is_some() {
+        packages.push(read_package(&manifest_path, &manifest,)?,);
+    }
+    for member in get_workspace_members(root, &manifest,) {
+        let member_manifest = member.join("Cargo.toml",);
+        if member_manifest == manifest_path || !member_manifest.is_file() {
+            continue;
+        }
+        let member_value = read_manifest(&member_manifest,)?;
+        packages.push(read_package(&member_manifest, &member_value,)?,);
+    }
+    Ok(packages,)
+}
+
+/// The packages of the manifest that depend on `seed_routing`.
+pub fn find_seed_packages(manifest_path: &Path,) -> anyhow::Result<Vec<Package,>,> {
+    Ok(read_packages(manifest_path,)?
+        .into_iter()
+        .filter(Package::uses_seed_routing,)
+        .collect(),)
+}
+
+/// Pick the package to generate from, `package` is mandatory for a workspace
+/// without a root package.
+pub fn resolve_package(manifest_path: &Path, package: Option<&str,>,) -> anyhow::Result<Package,> {
+    let packages = read_packages(manifest_path,)?;
+    let names = || {
+        packages
+            .iter()
+            .map(|p| p.name.as_str(),)
+            .collect::<Vec<&str,>>()
+            .join(", ",)
+    };
+    match package {
+        Some(name,) => packages
+            .iter()
+            .find(|p| p.name == name,)
+            .cloned()
+            .ok_or_else(|| anyhow!("package {} not found, available packages : {}", name, names()),),
+        None => {
+            let root_manifest = manifest_path.canonicalize()?;
+            match packages.iter().find(|p| p.manifest_path == root_manifest,) {
+                Some(root,) => Ok(root.clone(),),
+                None if packages.len() == 1 => Ok(packages[0].clone(),),
+                None => bail!("workspace has several packages, choose one with -p : {}", names()),
+            }
+        },
+    }
+}
+
+fn read_manifest(manifest_path: &Path,) -> anyhow::Result<Value,> {
+    let src = fs::read_to_string(manifest_path,)?;
+    src.parse::<Value>()
+        .map_err(|e| anyhow!("Unable to parse {} : {}", manifest_path.display(), e),)
+}
+
+fn read_package(manifest_path: &Path, manifest: &Value,) -> anyhow::Result<Package,> {
+    let name = manifest
+        .get("package",)
+        .and_then(|p| p.get("name",),)
+        .and_then(Value::as_str,)
+        .ok_or_else(|| anyhow!("No package name in {}", manifest_path.display()),)?;
+    let root = manifest_path.parent().expect("manifest should be in a directory",);
+    let entry_point = get_entry_point(root, manifest,).ok_or_else(|| {
+        anyhow!("No lib or bin target found for package {} in {}", name, root.display())
+    },)?;
+    Ok(Package {
+        name: name.to_string(),
+        manifest_path: manifest_path.to_path_buf(),
+        entry_point,
+        dependencies: get_dependencies(manifest,),
+    },)
+}
+
+fn get_dependencies(manifest: &Value,) -> Vec<String,> {
+    manifest
+        .get("dependencies",)
+        .and_then(Value::as_table,)
+        .map(|dependencies| {
+            dependencies
+                .iter()
+                .map(|(name, dependency,)| {
+                    dependency
+                        .get("package",)
+                        .and_then(Value::as_str,)
+                        .unwrap_or(name,)
+                        .to_string()
+                },)
+                .collect()
+        },)
+        .unwrap_or_default()
+}
+
+/// The `[lib]` source, then `src/lib.rs`, then the first `[[bin]]` source,
+/// then `src/main.rs`.
+fn get_entry_point(root: &Path, manifest: &Value,) -> Option<PathBuf,> {
+    let lib = manifest
+        .get("lib",)
+        .and_then(|lib| lib.get("path",),)
+        .and_then(Value::as_str,)
+        .map(|path| root.join(path,),)
+        .unwrap_or_else(|| root.join("src/lib.rs",),);
+    if lib.is_file() {
+        return Some(lib,);
+    }
+    let bin = manifest
+        .get("bin",)
+        .and_then(Value::as_array,)
+        .and_then(|bins| bins.iter().find_map(|bin| bin.get("path",).and_then(Value::as_str,),),)
+        .map(|path| root.join(path,),)
+        .unwrap_or_else(|| root.join("src/main.rs",),);
+    if bin.is_file() {
+        return Some(bin,);
+    }
+    None
+}
+
+/// The member directories of the workspace, `crates/*` globs are expanded
+/// one level.
+fn get_workspace_members(root: &Path, manifest: &Value,) -> Vec<PathBuf,> {
+    let members = manifest
+        .get("workspace",)
+        .and_then(|w| w.get("members",),)
+        .and_then(Value::as_array,);
+    let mut dirs = Vec::new();
+    for member in members.into_iter().flatten().filter_map(Value::as_str,) {
+        match member.strip_suffix("/*",) {
+            Some(parent,) => {
+                if let Ok(entries,) = fs::read_dir(root.join(parent,),) {
+                    let mut found: Vec<PathBuf,> = entries
+                        .filter_map(|e| e.ok().map(|e| e.path(),),)
+                        .filter(|p| p.is_dir(),)
+                        .collect();
+                    found.sort();
+                    dirs.extend(found,);
+                }
+            },
+            None => dirs.push(root.join(member,),),
+        }
+    }
+    dirs
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        manifest::{find_manifest, find_seed_packages, resolve_package},
+        test_dir::TestDir,
+    };
+    use std::fs;
+
+    #[test]
+    fn test_resolve_package() {
+        let dir = TestDir::new("manifest",);
+        fs::create_dir_all(dir.join("crates/app/src",),).unwrap();
+        fs::create_dir_all(dir.join("crates/server/bin",),).unwrap();
+        fs::write(
+            dir.join("Cargo.toml",),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("crates/app/Cargo.toml",),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\nseed = \
+             \"0.8.0\"\nrouting = { version = \"0.1.0\", package = \"seed_routing\" }\n",
+        )
+        .unwrap();
+        fs::write(dir.join("crates/app/src/lib.rs",), "",).unwrap();
+        fs::write(
+            dir.join("crates/server/Cargo.toml",),
+            "[package]\nname = \"server\"\nversion = \"0.1.0\"\n\n[[bin]]\nname = \
+             \"server\"\npath = \"bin/server.rs\"\n",
+        )
+        .unwrap();
+        fs::write(dir.join("crates/server/bin/server.rs",), "",).unwrap();
+        let dir = dir.canonicalize().unwrap();
+
+        assert_eq!(
+            find_manifest(&dir.join("crates/app/src",),),
+            Some(dir.join("crates/app/Cargo.toml",))
+        );
+
+        let app = resolve_package(&dir.join("crates/app/Cargo.toml",), None,).unwrap();
+        assert_eq!(app.name, "app");
+        assert_eq!(app.entry_point, dir.join("crates/app/src/lib.rs"));
+
+        let server = resolve_package(&dir.join("Cargo.toml",), Some("server",),).unwrap();
+        assert_eq!(server.entry_point, dir.join("crates/server/bin/server.rs"));
+
+        let error = resolve_package(&dir.join("Cargo.toml",), None,).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "workspace has several packages, choose one with -p : app, server"
+        );
+        assert!(resolve_package(&dir.join("Cargo.toml",), Some("web",),).is_err());
+
+        assert_eq!(app.dependencies, vec!["seed_routing", "seed"]);
+        let seed_packages = find_seed_packages(&dir.join("Cargo.toml",),).unwrap();
+        assert_eq!(seed_packages, vec![app]);
+    }
+}
@@ -2,11 +2,13 @@
 //! It will extract variants and attributes from Routes enum.
 
 use syn::{
-    Attribute, Error, Item, ItemEnum, ItemFn, ItemMod, ItemStruct, Lit, LitStr, Meta,
-    MetaNameValue, Result,
+    export::ToTokens, spanned::Spanned, Attribute, Error, FieldsNamed, Item, ItemEnum, ItemFn,
+    ItemMod, ItemStruct, Lit, LitStr, Meta, MetaNameValue, Result, UseTree,
 };
 
 pub mod module;
+pub mod route;
+pub mod update;
 pub mod view;
 
 pub fn get_string_from_attribute(
@@ -23,7 +25,33 @@ pub fn get_string_from_attribute(
         },) => Some(Some(name,),),
         _ => None,
     }
-    .ok_or_else(|| Error::new_spanned(attr, &format!("expected #[{} = \"...\"]", attribute_name),),)
+    .ok_or_else(|| Error::new_spanned(attr, format!("expected #[{} = \"...\"]", attribute_name),),)
+}
+
+/// The fields written as `name: String, page: Option<u32>` as name and type
+/// without whitespace.
+pub fn parse_named_fields(fields: &str,) -> Result<Vec<(String, String,),>,> {
+    let fields = syn::parse_str::<FieldsNamed,>(&format!("{{ {} }}", fields),)?;
+    Ok(fields
+        .named
+        .iter()
+        .map(|f| {
+            let mut ty = f.ty.to_token_stream().to_string();
+            ty.retain(|c| !c.is_whitespace(),);
+            (f.ident.as_ref().unwrap().to_string(), ty,)
+        },)
+        .collect(),)
+}
+
+/// Line and column, starting at 1, of the attribute in the parsed file.
+pub fn get_attribute_location(
+    attribute_name: &str,
+    mut attrs: std::slice::Iter<'_, Attribute,>,
+) -> Option<(usize, usize,),> {
+    attrs.find(|attr| attr.path.is_ident(attribute_name,),).map(|attr| {
+        let start = attr.span().start();
+        (start.line, start.column + 1,)
+    },)
 }
 
 pub fn find_routes(file: &syn::File,) -> Option<ItemEnum,> {
@@ -48,6 +76,71 @@ pub fn find_model(file: &syn::File,) -> Option<ItemStruct,> {
     },)
 }
 
+/// Every struct of the file, including the ones in inline modules.
+pub fn find_structs(file: &syn::File,) -> Vec<ItemStruct,> {
+    fn collect(items: &[Item], structs: &mut Vec<ItemStruct,>,) {
+        for item in items {
+            match item {
+                Item::Struct(found_struct,) => structs.push(found_struct.clone(),),
+                Item::Mod(ItemMod {
+                    content: Some((_, items,),),
+                    ..
+                },) => collect(items, structs,),
+                _ => {},
+            }
+        }
+    }
+    let mut structs = Vec::new();
+    collect(&file.items, &mut structs,);
+    structs
+}
+
+/// The `use` declarations of the items as alias -> path, the alias is `*` for
+/// globs. `use crate::user::{LoggedData as User, *};` gives
+/// `("User", ["crate", "user", "LoggedData"])` and `("*", ["crate", "user"])`.
+pub fn find_imports(items: &[Item],) -> Vec<(String, Vec<String,>,),> {
+    fn collect(tree: &UseTree, prefix: Vec<String,>, imports: &mut Vec<(String, Vec<String,>,),>,) {
+        match tree {
+            UseTree::Path(use_path,) => {
+                let mut prefix = prefix;
+                prefix.push(use_path.ident.to_string(),);
+                collect(&use_path.tree, prefix, imports,)
+            },
+            UseTree::Name(use_name,) if use_name.ident == "self" => {
+                if let Some(last,) = prefix.last() {
+                    imports.push((last.clone(), prefix.clone(),),)
+                }
+            },
+            UseTree::Name(use_name,) => {
+                let mut path = prefix;
+                path.push(use_name.ident.to_string(),);
+                imports.push((use_name.ident.to_string(), path,),)
+            },
+            UseTree::Rename(use_rename,) => {
+                let mut path = prefix;
+                path.push(use_rename.ident.to_string(),);
+                imports.push((use_rename.rename.to_string(), path,),)
+            },
+            UseTree::Glob(_,) => imports.push(("*".to_string(), prefix,),),
+            UseTree::Group(group,) => {
+                for tree in group.items.iter() {
+                    collect(tree, prefix.clone(), imports,)
+                }
+            },
+        }
+    }
+    let mut imports = Vec::new();
+    for item in items {
+        if let Item::Use(item_use,) = item {
+            // `::name` is always an external crate
+            if item_use.leading_colon.is_none() {
+                collect(&item_use.tree, Vec::new(), &mut imports,);
+            }
+        }
+    }
+    imports
+}
+
 pub fn find_function(file: &syn::File, fn_name: &str,) -> Option<ItemFn,> {
     file.items.iter().find_map(|item| -> Option<ItemFn,> {
         if let Item::Fn(found_fn,) = item {
@@ -59,6 +152,22 @@ pub fn find_function(file: &syn::File, fn_name: &str,) -> Option<ItemFn,> {
     },)
 }
 
+/// The enum or struct declared or imported with this name.
+pub fn find_type(file: &syn::File, name: &str,) -> Option<Item,> {
+    file.items.iter().find_map(|item| match item {
+        Item::Enum(found,) if found.ident == name => Some(item.clone(),),
+        Item::Struct(found,) if found.ident == name => Some(item.clone(),),
+        Item::Use(_,)
+            if find_imports(std::slice::from_ref(item,),)
+                .iter()
+                .any(|(alias, _,)| alias == name,) =>
+        {
+            Some(item.clone(),)
+        },
+        _ => None,
+    },)
+}
+
 pub fn find_message(file: &syn::File,) -> Option<ItemEnum,> {
     file.items.iter().find_map(|item| -> Option<ItemEnum,> {
         if let Item::Enum(found_enum,) = item {
@@ -70,6 +179,28 @@ pub fn find_message(file: &syn::File,) -> Option<ItemEnum,> {
     },)
 }
 
+/// The first module behind `#[cfg(test)]`, whatever its name.
+pub fn find_test_mod(file: &syn::File,) -> Option<ItemMod,> {
+    file.items.iter().find_map(|item| match item {
+        Item::Mod(found_mod,) if found_mod.attrs.iter().any(is_cfg_test,) => {
+            Some(found_mod.clone(),)
+        },
+        _ => None,
+    },)
+}
+
+fn is_cfg_test(attr: &syn::Attribute,) -> bool {
+    match attr.parse_meta() {
+        Ok(syn::Meta::List(list,),) if list.path.is_ident("cfg",) => {
+            list.nested.iter().any(|nested| match nested {
+                syn::NestedMeta::Meta(syn::Meta::Path(path,),) => path.is_ident("test",),
+                _ => false,
+            },)
+        },
+        _ => false,
+    }
+}
+
 pub fn find_mod(file: &syn::File, name: &str,) -> Option<ItemMod,> {
     file.items
         .clone()
@@ -88,7 +219,7 @@ pub fn find_mod(file: &syn::File, name: &str,) -> Option<ItemMod,> {
 mod test {
     use crate::{
         constants::{_FILE_WITHOUT_ROUTES_NOR_MODEL, _FILE_WITH_ROUTES_AND_MODEL},
-        parser::{find_model, find_routes},
+        parser::{find_imports, find_model, find_routes, find_structs},
     };
 
     #[test]
@@ -116,4 +247,33 @@ mod test {
 
         assert_eq!(model.is_some(), false)
     }
+
+    #[test]
+    fn test_find_structs() {
+        let parsed_file = syn::parse_file(
+            "struct Model {}\nmod user { pub struct LoggedData { role: Role } }\nenum Role {}",
+        )
+        .unwrap();
+        let structs: Vec<String,> =
+            find_structs(&parsed_file,).iter().map(|s| s.ident.to_string(),).collect();
+
+        assert_eq!(structs, vec!["Model", "LoggedData"]);
+    }
+
+    #[test]
+    fn test_find_imports() {
+        let parsed_file = syn::parse_file(
+            "use crate::user::{self, LoggedData as User, *};\nuse super::Session;\nuse \
+             ::seed::Url;",
+        )
+        .unwrap();
+        let path = |p: &str| p.split("::",).map(str::to_string,).collect::<Vec<String,>>();
+
+        assert_eq!(find_imports(&parsed_file.items,), vec![
+            ("user".to_string(), path("crate::user")),
+            ("User".to_string(), path("crate::user::LoggedData")),
+            ("*".to_string(), path("crate::user")),
+            ("Session".to_string(), path("super::Session")),
+        ]);
+    }
 }
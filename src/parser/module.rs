@@ -1,7 +1,7 @@
 use proc_macro_error::{abort, Diagnostic, Level};
 
 use crate::parser::get_string_from_attribute;
-use syn::Attribute;
+use syn::{Attribute, Meta, NestedMeta};
 
 /// Check the #[modules_path = "MY_DIRECTORY"] attribute
 /// If present, then we will build a module and imports.
@@ -25,3 +25,19 @@ pub fn modules_path(attrs: &std::slice::Iter<'_, Attribute,>,) -> Option<String,
     };
     if name.is_empty() { None } else { Some(name,) }
 }
+
+/// Check if the item has the given derive, `RoutingModules` for
+/// `#[derive(Debug, RoutingModules)]`.
+pub fn has_derive(attrs: &std::slice::Iter<'_, Attribute,>, derive: &str,) -> bool {
+    attrs.clone().filter(|attr| attr.path.is_ident("derive",),).any(|attr| {
+        match attr.parse_meta() {
+            Ok(Meta::List(list,),) => list.nested.iter().any(|nested| match nested {
+                NestedMeta::Meta(Meta::Path(path,),) => {
+                    path.segments.last().is_some_and(|segment| segment.ident == derive,)
+                },
+                _ => false,
+            },),
+            _ => false,
+        }
+    },)
+}
@@ -0,0 +1,104 @@
+use proc_macro_error::{abort, Diagnostic, Level};
+
+use crate::parser::{get_string_from_attribute, parse_named_fields};
+use syn::Attribute;
+
+/// Get the content of #[as_path = "PATH"].
+/// An empty string is a valid path and means the root of the parent.
+pub fn get_as_path_attribute(attrs: std::slice::Iter<'_, Attribute,>,) -> Option<String,> {
+    let mut attrs = attrs.filter_map(|attr| match get_string_from_attribute("as_path", attr,) {
+        Ok(op,) => op,
+        Err(err,) => abort!(Diagnostic::new(Level::Error, err.to_string())),
+    },);
+    if attrs.clone().count() > 1 {
+        abort!(Diagnostic::new(
+            Level::Error,
+            "Multiple as_path defined.".into()
+        ))
+    }
+    attrs.next().map(|path| path.value(),)
+}
+
+/// Check the #[default_route] attribute.
+pub fn is_default_route(mut attrs: std::slice::Iter<'_, Attribute,>,) -> bool {
+    attrs.any(|attr| attr.path.is_ident("default_route",),)
+}
+
+/// Get the struct path of #[crud = "models::user::User"].
+pub fn get_crud_attribute(attrs: std::slice::Iter<'_, Attribute,>,) -> Option<String,> {
+    let mut attrs = attrs.filter_map(|attr| match get_string_from_attribute("crud", attr,) {
+        Ok(op,) => op,
+        Err(err,) => abort!(Diagnostic::new(Level::Error, err.to_string())),
+    },);
+    if attrs.clone().count() > 1 {
+        abort!(Diagnostic::new(
+            Level::Error,
+            "Multiple crud defined.".into()
+        ))
+    }
+    attrs.next().map(|path| path.value().trim().trim_start_matches("crate::",).to_string(),)
+}
+
+/// Get the path of the type to fetch of #[fetch = "models::user::User"].
+pub fn get_fetch_attribute(attrs: std::slice::Iter<'_, Attribute,>,) -> Option<String,> {
+    let mut attrs = attrs.filter_map(|attr| match get_string_from_attribute("fetch", attr,) {
+        Ok(op,) => op,
+        Err(err,) => abort!(Diagnostic::new(Level::Error, err.to_string())),
+    },);
+    if attrs.clone().count() > 1 {
+        abort!(Diagnostic::new(
+            Level::Error,
+            "Multiple fetch defined.".into()
+        ))
+    }
+    attrs.next().map(|path| path.value().trim().trim_start_matches("crate::",).to_string(),)
+}
+
+/// Get the typed query parameters of #[query = "name: String, page: u32"] as
+/// name and type.
+pub fn get_query_attribute(
+    attrs: std::slice::Iter<'_, Attribute,>,
+) -> Option<Vec<(String, String,),>,> {
+    let mut attrs = attrs.filter_map(|attr| match get_string_from_attribute("query", attr,) {
+        Ok(op,) => op,
+        Err(err,) => abort!(Diagnostic::new(Level::Error, err.to_string())),
+    },);
+    if attrs.clone().count() > 1 {
+        abort!(Diagnostic::new(
+            Level::Error,
+            "Multiple query defined.".into()
+        ))
+    }
+    let parameters = attrs.next()?.value();
+    match parse_named_fields(&parameters,) {
+        Ok(fields,) => Some(fields,),
+        Err(_,) => abort!(Diagnostic::new(
+            Level::Error,
+            format!("expected #[query = \"name: Type, ...\"], found \"{}\"", parameters)
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parser::route::get_query_attribute;
+    use syn::ItemEnum;
+
+    #[test]
+    fn test_get_query_attribute() {
+        let routes = syn::parse_str::<ItemEnum,>(
+            "enum Routes {\n    #[query = \"name: String, page: Option<u32>\"]\n    Login { \
+             query: IndexMap<String, String> },\n    Home,\n}",
+        )
+        .unwrap();
+        let login = &routes.variants[0];
+        assert_eq!(
+            get_query_attribute(login.attrs.iter(),),
+            Some(vec![
+                ("name".to_string(), "String".to_string()),
+                ("page".to_string(), "Option<u32>".to_string()),
+            ])
+        );
+        assert_eq!(get_query_attribute(routes.variants[1].attrs.iter(),), None);
+    }
+}
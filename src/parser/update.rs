@@ -0,0 +1,125 @@
+//! Find the `match msg` of the root `update` to add the arms dispatching the
+//! messages to the modules.
+
+use crate::parser::find_function;
+use proc_macro2::LineColumn;
+use syn::{spanned::Spanned, Expr, ExprMatch, FnArg, Pat, Stmt};
+
+/// The `match` on the message in `fn update`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct UpdateMatch {
+    /// Variants of Msg already matched, `Login` for `Msg::Login(m) => ..`.
+    pub variants: Vec<String,>,
+    /// Position of the closing brace of the match.
+    pub end: LineColumn,
+    /// End of the last arm when a comma is needed before adding arms.
+    pub missing_comma: Option<LineColumn,>,
+}
+
+/// Find the match on the first argument of `fn update`.
+pub fn find_update_match(file: &syn::File,) -> Option<UpdateMatch,> {
+    let update = find_function(file, "update",)?;
+    let msg = match update.sig.inputs.first()? {
+        FnArg::Typed(arg,) => match arg.pat.as_ref() {
+            Pat::Ident(pat,) => pat.ident.to_string(),
+            _ => return None,
+        },
+        FnArg::Receiver(_,) => return None,
+    };
+    let found = update.block.stmts.iter().find_map(|stmt| match stmt {
+        Stmt::Expr(Expr::Match(found,),) | Stmt::Semi(Expr::Match(found,), _,)
+            if is_ident(&found.expr, &msg,) =>
+        {
+            Some(found,)
+        },
+        _ => None,
+    },)?;
+    // the span of the braces goes from `{` to after `}`
+    let mut end = found.brace_token.span.end();
+    end.column -= 1;
+    Some(UpdateMatch {
+        variants: found.arms.iter().flat_map(|arm| get_variants(&arm.pat,),).collect(),
+        end,
+        missing_comma: get_missing_comma(found,),
+    },)
+}
+
+/// The byte offset of the position in the source.
+pub fn get_offset(src: &str, position: LineColumn,) -> usize {
+    let line_start: usize = src
+        .split_inclusive('\n',)
+        .take(position.line - 1,)
+        .map(str::len,)
+        .sum();
+    let column: usize = src[line_start..]
+        .chars()
+        .take(position.column,)
+        .map(char::len_utf8,)
+        .sum();
+    line_start + column
+}
+
+fn is_ident(expr: &Expr, ident: &str,) -> bool {
+    match expr {
+        Expr::Path(path,) => path.path.is_ident(ident,),
+        _ => false,
+    }
+}
+
+fn get_variants(pat: &Pat,) -> Vec<String,> {
+    let path = match pat {
+        Pat::TupleStruct(pat,) => &pat.path,
+        Pat::Struct(pat,) => &pat.path,
+        Pat::Path(pat,) => &pat.path,
+        Pat::Or(pat,) => return pat.cases.iter().flat_map(get_variants,).collect(),
+        _ => return Vec::new(),
+    };
+    path.segments
+        .last()
+        .map(|segment| vec![segment.ident.to_string()],)
+        .unwrap_or_default()
+}
+
+/// Only the arms without a block need a comma to be followed by another arm.
+fn get_missing_comma(found: &ExprMatch,) -> Option<LineColumn,> {
+    let last = found.arms.last()?;
+    match (&last.comma, last.body.as_ref(),) {
+        (None, Expr::Block(_,),) | (Some(_,), _,) => None,
+        (None, body,) => Some(body.span().end(),),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parser::update::{find_update_match, get_offset};
+
+    #[test]
+    fn test_find_update_match() {
+        let src = r###"
+fn update(message: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match message {
+        Msg::UrlChanged(subs::UrlChanged(url)) => {}
+        Msg::Login(login_msg) => pages::login::update(login_msg, &mut model.login, orders),
+        Msg::GoBack | Msg::GoForward => {}
+        Msg::Logout => model.logged_user = None
+    }
+}
+"###;
+        let parsed_file = syn::parse_file(src,).unwrap();
+        let update_match = find_update_match(&parsed_file,).unwrap();
+
+        assert_eq!(update_match.variants, vec![
+            "UrlChanged",
+            "Login",
+            "GoBack",
+            "GoForward",
+            "Logout"
+        ]);
+        assert_eq!(&src[get_offset(src, update_match.end,)..], "}\n}\n");
+        let comma = get_offset(src, update_match.missing_comma.unwrap(),);
+        assert_eq!(&src[comma..], "\n    }\n}\n");
+
+        let parsed_file = syn::parse_file("fn update(msg: Msg) { if true {} }",).unwrap();
+        assert!(find_update_match(&parsed_file,).is_none());
+    }
+}
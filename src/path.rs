@@ -0,0 +1,136 @@
+//! Resolve the paths of the files to write from the target file given to the
+//! cli.
+
+use std::{
+    env,
+    path::{Component, Path, PathBuf},
+};
+
+/// Clean the path without touching the file system, `./my_app/src//pages/`
+/// -> `./my_app/src/pages`.
+/// The leading `./` is kept so logs show the path the way it was given.
+pub fn normalize(path: &Path,) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_,),) => {
+                    normalized.pop();
+                },
+                Some(Component::RootDir,) | Some(Component::Prefix(_,),) => {},
+                _ => normalized.push("..",),
+            },
+            Component::CurDir if normalized.as_os_str().is_empty() => normalized.push(".",),
+            Component::CurDir => {},
+            c => normalized.push(c.as_os_str(),),
+        }
+    }
+    if normalized.as_os_str().is_empty() {
+        normalized.push(".",);
+    }
+    normalized
+}
+
+/// The directory of the target file, `.` for a bare `lib.rs`.
+pub fn parent_dir(file_path: &Path,) -> PathBuf {
+    match normalize(file_path,).parent() {
+        Some(parent,) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from(".",),
+    }
+}
+
+/// Absolute path used to know if two paths point to the same file, even when
+/// the file does not exist yet.
+pub fn canonical_key(path: &Path,) -> PathBuf {
+    if let Ok(canonical,) = path.canonicalize() {
+        return canonical;
+    }
+    let normalized = normalize(path,);
+    if let (Some(parent,), Some(name,),) = (normalized.parent(), normalized.file_name(),) {
+        let parent = if parent.as_os_str().is_empty() { Path::new(".",) } else { parent };
+        if let Ok(parent,) = parent.canonicalize() {
+            return parent.join(name,);
+        }
+    }
+    match env::current_dir() {
+        Ok(current_dir,) => normalize(&current_dir.join(normalized,),),
+        Err(_,) => normalized,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        path::{canonical_key, normalize, parent_dir},
+        test_dir::TestDir,
+    };
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(Path::new("./my_app/src/")), PathBuf::from("./my_app/src"));
+        assert_eq!(normalize(Path::new("./my_app//src/./pages")), PathBuf::from("./my_app/src/pages"));
+        assert_eq!(normalize(Path::new("my_app/src/../lib.rs")), PathBuf::from("my_app/lib.rs"));
+        assert_eq!(normalize(Path::new("../app/./src")), PathBuf::from("../app/src"));
+        assert_eq!(normalize(Path::new("/app/../../src")), PathBuf::from("/src"));
+        assert_eq!(normalize(Path::new("")), PathBuf::from("."));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_non_utf8_path() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let name = OsStr::from_bytes(b"app_\xff",);
+        let path = Path::new(".",).join(name,).join("src//./lib.rs",);
+        assert!(path.to_str().is_none());
+        assert_eq!(normalize(&path), Path::new("./",).join(name,).join("src/lib.rs"));
+        assert_eq!(parent_dir(&path), Path::new("./",).join(name,).join("src"));
+
+        let dir = TestDir::new("path_non_utf8",);
+        fs::create_dir_all(dir.join(name,).join("src",),).unwrap();
+        fs::write(dir.join(name,).join("src/lib.rs",), "",).unwrap();
+        let canonical = dir.canonicalize().unwrap().join(name,).join("src",);
+        assert_eq!(
+            canonical_key(&dir.join(name,).join("src//./lib.rs")),
+            canonical.join("lib.rs")
+        );
+        // not created yet
+        assert_eq!(
+            canonical_key(&dir.join(name,).join("src/../src/login.rs")),
+            canonical.join("login.rs")
+        );
+    }
+
+    #[test]
+    fn test_parent_dir() {
+        assert_eq!(parent_dir(Path::new("lib.rs")), PathBuf::from("."));
+        assert_eq!(parent_dir(Path::new("./lib.rs")), PathBuf::from("."));
+        assert_eq!(parent_dir(Path::new("./my_app/src//lib.rs")), PathBuf::from("./my_app/src"));
+        assert_eq!(parent_dir(Path::new("/my_app/src/lib.rs")), PathBuf::from("/my_app/src"));
+    }
+
+    #[test]
+    fn test_canonical_key() {
+        let dir = TestDir::new("path",);
+        fs::create_dir_all(dir.join("src",),).unwrap();
+        fs::write(dir.join("src/lib.rs",), "",).unwrap();
+
+        assert_eq!(
+            canonical_key(&dir.join("src//lib.rs")),
+            canonical_key(&dir.join("src/../src/./lib.rs"))
+        );
+        // not created yet
+        assert_eq!(
+            canonical_key(&dir.join("src/login.rs")),
+            canonical_key(&dir.join("./src//login.rs"))
+        );
+        assert_eq!(
+            canonical_key(&dir.join("src/login.rs")),
+            dir.canonicalize().unwrap().join("src/login.rs")
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Temporary directories for the tests writing files.
+
+use std::{
+    fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0,);
+
+/// A directory in the temp dir unique to the test, removed with its content
+/// when dropped so a failing test does not leave it behind.
+pub struct TestDir {
+    path: PathBuf,
+}
+
+impl TestDir {
+    /// Create `proto_seeder_<name>_<pid>_<count>` so concurrent `cargo test`
+    /// runs and tests of the same run never share a directory.
+    pub fn new(name: &str,) -> TestDir {
+        let path = std::env::temp_dir().join(format!(
+            "proto_seeder_{}_{}_{}",
+            name,
+            process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst,)
+        ),);
+        let _ = fs::remove_dir_all(&path,);
+        fs::create_dir_all(&path,).expect("Should create the test directory",);
+        TestDir {
+            path,
+        }
+    }
+}
+
+impl Deref for TestDir {
+    type Target = Path;
+
+    fn deref(&self,) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path,> for TestDir {
+    fn as_ref(&self,) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self,) {
+        let _ = fs::remove_dir_all(&self.path,);
+    }
+}
@@ -1,7 +1,9 @@
 //! Check with syn the parsed file for content.
 use crate::{
     content::module::import::ImportModule,
-    parser::{find_function, find_message, find_mod, find_model, find_routes},
+    parser::{
+        find_function, find_message, find_mod, find_model, find_routes, find_test_mod, find_type,
+    },
 };
 
 /// Check on parsed file for existing content.
@@ -15,7 +17,7 @@ impl Checker {
     pub fn return_mod_if_exist(src: &str, parent_module: ImportModule,) -> Vec<String,> {
         let mut list: Vec<String,> = Vec::new();
 
-        let parsed_file = syn::parse_file(&src,).expect("Should read content for file ",);
+        let parsed_file = syn::parse_file(src,).expect("Should read content for file ",);
 
         for imp in parent_module.imports_names.iter() {
             if find_mod(&parsed_file, imp,).is_some() {
@@ -29,7 +31,7 @@ impl Checker {
     /// Read the content of the file and parse it with syn so we can look for
     /// content in it.
     pub fn store_content_for_check(src: &str,) -> Checker {
-        let parsed_file = syn::parse_file(&src,).expect(
+        let parsed_file = syn::parse_file(src,).expect(
             "Should read content
 for file ",
         );
@@ -59,6 +61,10 @@ for file ",
             list.push(view.sig.ident.to_string(),);
         }
 
+        if find_test_mod(&parsed_file,).is_some() {
+            list.push("test".to_string(),);
+        }
+
         Checker {
             source_file: parsed_file,
             will_duplicate: list,
@@ -103,14 +109,38 @@ for file ",
         self.check_duplicate("view",)
     }
 
+    /// Check if a module behind `#[cfg(test)]` exists.
+    pub fn test_exist(&self,) -> bool {
+        self.check_duplicate("test",)
+    }
+
+    /// The parsed file the checks are done on.
+    pub fn source_file(&self,) -> &syn::File {
+        &self.source_file
+    }
+
     /// Check if a function with given name exists.
     pub fn check_local_function_exist(name: &str, src: &str,) -> bool {
-        let parsed_file = syn::parse_file(&src,).expect(
+        let parsed_file = syn::parse_file(src,).expect(
             "Should read content
                             for file ",
         );
 
-        matches!(find_function(&parsed_file, name), Some(_,))
+        find_function(&parsed_file, name).is_some()
+    }
+
+    /// Check if an enum or a struct with given name is declared or imported.
+    pub fn check_local_type_exist(name: &str, src: &str,) -> bool {
+        let parsed_file = syn::parse_file(src,).expect("Should read content for file ",);
+
+        find_type(&parsed_file, name,).is_some()
+    }
+
+    /// Check if a module with given name exists.
+    pub fn check_local_mod_exist(name: &str, src: &str,) -> bool {
+        let parsed_file = syn::parse_file(src,).expect("Should read content for file ",);
+
+        find_mod(&parsed_file, name,).is_some()
     }
 }
 
@@ -121,6 +151,16 @@ mod test {
     fn test_if_exist_update() {
         let check = Checker::store_content_for_check(_FILE_WITHOUT_UPDATE,);
         assert_eq!(check.update_exist(), true);
+        assert!(!check.test_exist());
+
+        let check = Checker::store_content_for_check("#[cfg(test)]\nmod test {}\n",);
+        assert!(check.test_exist());
+
+        let check = Checker::store_content_for_check("#[cfg(test)]\nmod tests {}\n",);
+        assert!(check.test_exist());
+
+        let check = Checker::store_content_for_check("mod tests {}\n",);
+        assert!(!check.test_exist());
     }
     #[test]
     fn test_if_exist_model() {
@@ -142,4 +182,19 @@ mod test {
 
         assert_eq!(check, false);
     }
+
+    #[test]
+    fn test_if_exist_type() {
+        assert!(Checker::check_local_type_exist("State", "enum State<T,> { Loaded(T,), }",));
+        assert!(Checker::check_local_type_exist("State", "use crate::request::State;",));
+        assert!(!Checker::check_local_type_exist("State", _FILE_WITH_GUARD,));
+    }
+
+    #[test]
+    fn test_if_exist_mod() {
+        let src = "#[cfg(test)]\nmod routes_test {}\n";
+
+        assert!(Checker::check_local_mod_exist("routes_test", src,));
+        assert!(!Checker::check_local_mod_exist("routes_test", _FILE_WITH_GUARD,));
+    }
 }
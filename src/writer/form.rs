@@ -0,0 +1,313 @@
+//! Add a form to a module file, the parts already there are left untouched.
+
+use crate::{
+    content::form::SeedForm,
+    parser::{
+        find_imports, find_message, find_model, find_structs,
+        update::{find_update_match, get_offset},
+    },
+    writer::{checker::Checker, insert_before_brace},
+};
+use syn::{spanned::Spanned, Fields, Item};
+
+/// The source of the module with the form and what was added or kept.
+#[derive(Debug, Clone, Default)]
+pub struct FormUpdate {
+    pub src: String,
+    pub added: Vec<String,>,
+    pub ignored: Vec<String,>,
+}
+
+/// Add to the source of the module the import of the struct, the field of the
+/// Model, the variants of Msg, the arms of `update` and the view that are
+/// missing.
+pub fn add_form(src: &str, form: &SeedForm,) -> FormUpdate {
+    let mut update = FormUpdate {
+        src: src.to_string(),
+        ignored: form.ignored.clone(),
+        ..FormUpdate::default()
+    };
+    add_import(&mut update, form,);
+    add_model_field(&mut update, form,);
+    add_variants(&mut update, form,);
+    add_arms(&mut update, form,);
+    if Checker::check_local_function_exist(&form.view_name, &update.src,) {
+        update
+            .ignored
+            .push(format!("No need to create view [ => ] as fn {} ()", form.view_name),);
+    } else {
+        update.src.push_str(&form.view,);
+        update.added.push(format!("fn {}", form.view_name),);
+    }
+    update
+}
+
+fn parse(src: &str,) -> syn::File {
+    syn::parse_file(src,).expect("Should read content for file ",)
+}
+
+/// Import the struct after the last `use` unless it is already in scope.
+fn add_import(update: &mut FormUpdate, form: &SeedForm,) {
+    let file = parse(&update.src,);
+    if find_imports(&file.items,).iter().any(|(alias, _,)| *alias == form.item,)
+        || find_structs(&file,).iter().any(|s| s.ident == form.item,)
+    {
+        return;
+    }
+    let last_use = file.items.iter().rev().find(|item| matches!(item, Item::Use(_)),);
+    let import = form.import.trim_end();
+    update.src = match (last_use, file.items.first(),) {
+        (Some(last_use,), _,) => {
+            let mut src = update.src.clone();
+            src.insert_str(get_offset(&src, last_use.span().end(),), &format!("\n{}", import),);
+            src
+        },
+        (None, Some(first,),) => {
+            let mut src = update.src.clone();
+            src.insert_str(get_offset(&src, first.span().start(),), &format!("{}\n\n", import),);
+            src
+        },
+        (None, None,) => format!("{}{}\n", update.src, import),
+    };
+    update.added.push(form.import.trim_end().to_string(),);
+}
+
+fn add_model_field(update: &mut FormUpdate, form: &SeedForm,) {
+    let checker = Checker::store_content_for_check(&update.src,);
+    if !checker.model_exist() {
+        update.src.push_str(&form.model,);
+        update.added.push("struct Model".to_string(),);
+        return;
+    }
+    let model = find_model(checker.source_file(),).expect("the Model should exist",);
+    let fields = match &model.fields {
+        Fields::Named(fields,) => fields,
+        _ => {
+            update.ignored.push(format!(
+                "No field {} added [ => ] as Model has no named fields",
+                form.field
+            ),);
+            return;
+        },
+    };
+    if fields.named.iter().any(|f| f.ident.as_ref().unwrap() == &form.field,) {
+        return;
+    }
+    let mut end = fields.brace_token.span.end();
+    end.column -= 1;
+    let missing_comma = match fields.named.last() {
+        Some(last,) if !fields.named.trailing_punct() => Some(last.span().end(),),
+        _ => None,
+    };
+    update.src = insert_before_brace(&update.src, end, missing_comma, &form.model_field,);
+    update.added.push(format!("field {} of Model", form.field),);
+}
+
+fn add_variants(update: &mut FormUpdate, form: &SeedForm,) {
+    let checker = Checker::store_content_for_check(&update.src,);
+    if !checker.message_exist() {
+        update.src.push_str(&form.msg,);
+        update.added.push("enum Msg".to_string(),);
+        return;
+    }
+    let msg = find_message(checker.source_file(),).expect("the Msg should exist",);
+    let variants: String = form
+        .variants
+        .iter()
+        .filter(|(variant, _,)| !msg.variants.iter().any(|v| v.ident == variant,),)
+        .map(|(_, (declaration, _,),)| declaration.as_str(),)
+        .collect();
+    if variants.is_empty() {
+        update
+            .ignored
+            .push("No need to add variants [ => ] as enum Msg has them all".to_string(),);
+        return;
+    }
+    let mut end = msg.brace_token.span.end();
+    end.column -= 1;
+    let missing_comma = match msg.variants.last() {
+        Some(last,) if !msg.variants.trailing_punct() => Some(last.span().end(),),
+        _ => None,
+    };
+    update.src = insert_before_brace(&update.src, end, missing_comma, &variants,);
+    update.added.push("variants of enum Msg".to_string(),);
+}
+
+fn add_arms(update: &mut FormUpdate, form: &SeedForm,) {
+    let checker = Checker::store_content_for_check(&update.src,);
+    if !checker.update_exist() {
+        update.src.push_str(&form.update,);
+        update.added.push("fn update".to_string(),);
+        return;
+    }
+    let update_match = match find_update_match(checker.source_file(),) {
+        Some(update_match,) => update_match,
+        None => {
+            update
+                .ignored
+                .push("No match on msg in fn update [ => ] no form arms added".to_string(),);
+            return;
+        },
+    };
+    let arms: String = form
+        .variants
+        .iter()
+        .filter(|(variant, _,)| !update_match.variants.contains(variant,),)
+        .map(|(_, (_, arm,),)| arm.as_str(),)
+        .collect();
+    if arms.is_empty() {
+        update
+            .ignored
+            .push("No need to add form arms [ => ] as fn update has them all".to_string(),);
+        return;
+    }
+    update.src = insert_before_brace(
+        &update.src,
+        update_match.end,
+        update_match.missing_comma,
+        &arms,
+    );
+    update.added.push("arms of fn update".to_string(),);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        content::{form::get_form, scope::CrateIndex, ContentSettings},
+        writer::form::add_form,
+    };
+
+    const CREDENTIALS: &str = r###"
+pub struct LoginCredentials {}
+
+mod models {
+    pub mod auth {
+        pub struct LoginCredentials {
+            target: String,
+            password: String,
+            pub remember: bool,
+        }
+
+        impl LoginCredentials {
+            pub fn target(&self,) -> &str {
+                &self.target
+            }
+
+            pub fn set_target(&mut self, target: String,) {
+                self.target = target;
+            }
+        }
+    }
+}
+
+impl LoginCredentials {
+    pub fn password(&self,) -> &str {
+        ""
+    }
+
+    pub fn set_password(&mut self, _password: String,) {}
+}
+"###;
+
+    const LOGIN: &str = r###"use seed::{prelude::*, *};
+
+pub struct Model {
+    pub error: Option<String>
+}
+
+pub enum Msg {
+    Login
+}
+
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg,>,) {
+    match msg {
+        Msg::Login => model.error = None
+    }
+}
+"###;
+
+    const LOGIN_WITH_FORM: &str = r###"use seed::{prelude::*, *};
+use crate::models::auth::LoginCredentials;
+
+pub struct Model {
+    pub error: Option<String>,
+    pub login_credentials: LoginCredentials,
+}
+
+pub enum Msg {
+    Login,
+    TargetChanged(String),
+    RememberChanged(String),
+}
+
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg,>,) {
+    match msg {
+        Msg::Login => model.error = None,
+        Msg::TargetChanged(value,) => model.login_credentials.set_target(value,),
+        Msg::RememberChanged(value,) => {
+            if let Ok(value,) = value.parse() {
+                model.login_credentials.remember = value;
+            }
+        },
+    }
+}
+
+/// Form editing the LoginCredentials of the Model.
+fn login_credentials_form(model: &Model,) -> Node<Msg,> {
+    form![
+        ev(Ev::Submit, |event| event.prevent_default(),),
+        label![
+            "Target",
+            input![
+                attrs! {At::Value => model.login_credentials.target().to_string()},
+                input_ev(Ev::Input, Msg::TargetChanged,),
+            ],
+        ],
+        label![
+            "Remember",
+            input![
+                attrs! {At::Value => model.login_credentials.remember.to_string()},
+                input_ev(Ev::Input, Msg::RememberChanged,),
+            ],
+        ],
+    ]
+}
+"###;
+
+    #[test]
+    fn test_add_form() {
+        let parsed_file = syn::parse_file(CREDENTIALS,).unwrap();
+        let mut index = CrateIndex::default();
+        index.add_module(Vec::new(), &parsed_file.items,);
+        let settings = ContentSettings {
+            index,
+            ..ContentSettings::default()
+        };
+        let (_, item,) =
+            settings.index.resolve_struct(&[], "models::auth::LoginCredentials",).unwrap();
+        let form = get_form(item, "crate::models::auth::LoginCredentials", None, &settings,);
+
+        let update = add_form(LOGIN, &form,);
+        assert_eq!(update.src, LOGIN_WITH_FORM);
+        assert_eq!(update.added.len(), 5);
+        assert_eq!(update.ignored, vec![
+            "No input for field password [ => ] as it is private and LoginCredentials has no fn \
+             set_password"
+        ]);
+
+        let update = add_form(&update.src, &form,);
+        assert_eq!(update.src, LOGIN_WITH_FORM);
+        assert!(update.added.is_empty());
+        assert_eq!(update.ignored.len(), 4);
+
+        let update = add_form("", &form,);
+        assert_eq!(update.added, vec![
+            "use crate::models::auth::LoginCredentials;",
+            "struct Model",
+            "enum Msg",
+            "fn update",
+            "fn login_credentials_form",
+        ]);
+        assert!(syn::parse_file(&update.src,).is_ok());
+    }
+}
@@ -8,10 +8,20 @@ use crate::{
         },
         view::SeedView,
     },
-    writer::{checker::Checker, FileOperation, ModulesWriter},
+    parser::{
+        find_function,
+        update::{find_update_match, get_offset},
+    },
+    path::parent_dir,
+    writer::{checker::Checker, insert_before_brace, FileOperation, ModulesWriter},
 };
 use indexmap::map::IndexMap;
-use std::io::{Read, Write};
+use std::{
+    fs,
+    io::{Read, Write},
+    path::Path,
+};
+use syn::{export::ToTokens, spanned::Spanned, Item};
 
 /// Manage the SeedContent on modules.
 /// Uses the checker to know if need to create or ignore content.
@@ -39,28 +49,33 @@ impl ContentManager {
     /// State updated.
     fn write_on_file_with_custom_message(
         &mut self,
-        file_path: &str,
+        file_path: &Path,
         file_content: &str,
         message: &str,
     ) -> &mut Self {
-        if let Some((_, file,),) = self.writer.files.get_mut(file_path,) {
+        if let Some((_, file,),) = self.writer.get_file_mut(file_path,) {
             if let Err(e,) = file.write_all(file_content.as_ref(),) {
                 self.writer.log_error(
                     format!(
                         "error {:?} when writing {} at {} for {}",
-                        e, file_content, file_path, message
+                        e,
+                        file_content,
+                        file_path.display(),
+                        message
                     )
                     .as_str(),
                 );
             } else {
-                self.writer
-                    .log_ok(format!("updated {} for {}", file_path, message).as_str(),);
+                self.writer.log_ok(
+                    format!("updated {} for {}", file_path.display(), message).as_str(),
+                );
             }
         } else {
             self.writer.log_error(
                 format!(
                     "file not found at {} when trying to {} ",
-                    file_path, message
+                    file_path.display(),
+                    message
                 )
                 .as_str(),
             );
@@ -73,23 +88,25 @@ impl ContentManager {
     /// Log ok or error.
     /// State updated.
     /// //TODO could save error state.
-    fn write_on_file(&mut self, file_path: &str, file_content: &str,) -> &mut Self {
-        if let Some((_, file,),) = self.writer.files.get_mut(file_path,) {
+    fn write_on_file(&mut self, file_path: &Path, file_content: &str,) -> &mut Self {
+        if let Some((_, file,),) = self.writer.get_file_mut(file_path,) {
             if let Err(e,) = file.write_all(file_content.as_ref(),) {
                 self.writer.log_error(
                     format!(
                         "error {:?} when writing {} at {} ",
-                        e, file_content, file_path
+                        e,
+                        file_content,
+                        file_path.display()
                     )
                     .as_str(),
                 );
             } else {
                 self.writer
-                    .log_ok(format!("updated {} ", file_path).as_str(),);
+                    .log_ok(format!("updated {} ", file_path.display()).as_str(),);
             }
         } else {
             self.writer
-                .log_error(format!("file not found at {}", file_path).as_str(),);
+                .log_error(format!("file not found at {}", file_path.display()).as_str(),);
         }
 
         self
@@ -101,18 +118,18 @@ impl ContentManager {
 
     /// Manage the imports for files.
     fn add_or_update_imports_from_module(&mut self, import_module: ImportModule,) -> &mut Self {
-        let path = import_module.meta().filepath().to_string();
+        let path = import_module.meta().filepath().to_path_buf();
 
         match import_module.parent_type {
             ParentModuleType::TargetFile => {
                 self.writer
-                    .open_file_with_panic(self.writer.target_file_path.clone().as_str(),);
+                    .open_file_with_panic(&self.writer.target_file_path.clone(),);
             },
 
             ParentModuleType::Folder => {
                 self.writer
                     .create_folder(import_module.folder_path(),)
-                    .create_or_update_file(path.clone(),);
+                    .create_or_update_file(&path,);
 
                 // Should be recursive but work only with lib.rs as now.
                 // Import_module of the folder for now.
@@ -121,7 +138,7 @@ impl ContentManager {
                 if !import_module.meta().clone().mod_import().is_empty() {
                     eprintln!("---------- detected module folder ");
                     let mut root_module = ImportModule::new_target_file_module(
-                        import_module.meta().import_file_location(),
+                        &parent_dir(import_module.meta().import_file_location(),),
                         import_module.meta().import_file_location(),
                     )
                     .unwrap_or_else(|_| {
@@ -143,19 +160,18 @@ impl ContentManager {
         }
         let (op, file,) = self
             .writer
-            .files
-            .get_mut(&path,)
-            .expect(&*format!("should have get the file at the path {}", &path),);
+            .get_file_mut(&path,)
+            .unwrap_or_else(|| panic!("should have get the file at the path {}", path.display()));
         let mut imports = import_module.imports_to_write();
         match op {
             FileOperation::Update => {
                 let mut src = String::new();
                 let read = file.read_to_string(&mut src,);
-                if read.is_err() {
+                if let Err(e,) = read {
                     self.writer
-                        .log_error(format!("Should read file for  {}", path).as_str(),);
+                        .log_error(format!("Should read file for  {}", path.display()).as_str(),);
                     self.writer
-                        .log_error(format!("{:?}", read.unwrap_err()).as_str(),);
+                        .log_error(format!("{:?}", e).as_str(),);
                 }
                 imports =
                     self.update_imports_to_write(imports.as_str(), &src, import_module.clone(),);
@@ -173,7 +189,7 @@ impl ContentManager {
 
         if !imports.is_empty() {
             self.write_on_file_with_custom_message(
-                path.as_str(),
+                &path,
                 imports.as_str(),
                 format!("import import_module module => {}", imports).as_str(),
             );
@@ -190,39 +206,49 @@ impl ContentManager {
     /// - put enum Msg.
     /// - pub fn update.
     /// - pub fn view.
-    fn insert_content(&mut self, path: &str, module: SeedModule,) {
+    /// - mod test.
+    fn insert_content(&mut self, path: &Path, module: SeedModule,) {
         const IMPORT_SEED: &str = r###"use seed::{prelude::*, *};"###;
         const IMPORT_SEED_ROUTING: &str = r###"use seed_routing::*;"###;
-        self.write_on_file(&path, format!("{}\n", IMPORT_SEED).as_str(),)
-            .write_on_file(&path, format!("{}\n", IMPORT_SEED_ROUTING).as_str(),)
-            .write_on_file_with_custom_message(&path, module.init(), "adding pub fn init()",)
-            .write_on_file_with_custom_message(&path, module.model(), "adding pub struct Model{}",)
-            .write_on_file_with_custom_message(&path, module.routes(), "adding pub enum Routes{} ",)
-            .write_on_file_with_custom_message(&path, module.msg(), "adding pub enum Msg{}",)
-            .write_on_file_with_custom_message(&path, module.update(), "adding pub fn update()",)
-            .write_on_file_with_custom_message(&path, module.view(), "adding pub fn view()",);
+        self.write_on_file(path, format!("{}\n", IMPORT_SEED).as_str(),)
+            .write_on_file(path, format!("{}\n", IMPORT_SEED_ROUTING).as_str(),)
+            .write_on_file_with_custom_message(path, module.init(), "adding pub fn init()",)
+            .write_on_file_with_custom_message(path, module.model(), "adding pub struct Model{}",)
+            .write_on_file_with_custom_message(path, module.routes(), "adding pub enum Routes{} ",)
+            .write_on_file_with_custom_message(path, module.msg(), "adding pub enum Msg{}",)
+            .write_on_file_with_custom_message(path, module.update(), "adding pub fn update()",)
+            .write_on_file_with_custom_message(path, module.view(), "adding pub fn view()",)
+            .write_on_file_with_custom_message(path, module.tests(), "adding mod test",);
         self.file_created += 1;
     }
 
     /// Use the Checker to verify if content already exist and then update if
     /// needed
-    fn update_content_if_needed(&mut self, path: &str, src: &str, module: SeedModule,) {
+    fn update_content_if_needed(&mut self, path: &Path, src: &str, module: SeedModule,) {
         let check = Checker::store_content_for_check(src,);
         let mut number_update = 0;
         if check.init_exist() {
-            self.writer.log_info("file already has init",);
+            if self.replace_placeholder(path, "init", module.init(), module.placeholders(),) {
+                number_update += 1;
+            } else {
+                self.writer.log_info("file already has init",);
+            }
         } else {
             number_update += 1;
-            self.write_on_file_with_custom_message(&path, module.init(), "adding pub fn init()",);
+            self.write_on_file_with_custom_message(path, module.init(), "adding pub fn init()",);
         }
 
         if check.model_exist() {
-            self.writer.log_info("file already has Model",);
+            if self.replace_placeholder(path, "Model", module.model(), module.placeholders(),) {
+                number_update += 1;
+            } else {
+                self.writer.log_info("file already has Model",);
+            }
         } else {
             number_update += 1;
 
             self.write_on_file_with_custom_message(
-                &path,
+                path,
                 module.model(),
                 "adding pub struct Model{}",
             );
@@ -233,7 +259,7 @@ impl ContentManager {
             number_update += 1;
 
             self.write_on_file_with_custom_message(
-                &path,
+                path,
                 module.routes(),
                 "adding pub enum Routes{} ",
             );
@@ -244,7 +270,7 @@ impl ContentManager {
             number_update += 1;
 
             self.write_on_file_with_custom_message(
-                &path,
+                path,
                 module.routes(),
                 "adding pub enum Msg{} ",
             );
@@ -254,17 +280,32 @@ impl ContentManager {
         } else {
             number_update += 1;
             self.write_on_file_with_custom_message(
-                &path,
+                path,
                 module.update(),
                 "adding pub fn update() ",
             );
         }
         if check.view_exist() {
-            self.writer.log_info("file already has view",);
+            if self.replace_placeholder(path, "view", module.view(), module.placeholders(),) {
+                number_update += 1;
+            } else {
+                self.writer.log_info("file already has view",);
+            }
         } else {
             number_update += 1;
 
-            self.write_on_file_with_custom_message(&path, module.view(), "adding pub fn view() ",);
+            self.write_on_file_with_custom_message(path, module.view(), "adding pub fn view() ",);
+        }
+        if check.test_exist() {
+            if self.replace_placeholder(path, "test", module.tests(), module.placeholders(),) {
+                number_update += 1;
+            } else {
+                self.writer.log_info("file already has mod test",);
+            }
+        } else {
+            number_update += 1;
+
+            self.write_on_file_with_custom_message(path, module.tests(), "adding mod test ",);
         }
 
         if number_update == 0 {
@@ -275,6 +316,63 @@ impl ContentManager {
         }
     }
 
+    /// Replace the function, the struct or the module by the one from the
+    /// content when the file still has it as generated from one of the
+    /// placeholders.
+    fn replace_placeholder(
+        &mut self,
+        path: &Path,
+        name: &str,
+        content: &str,
+        placeholders: &[String],
+    ) -> bool {
+        let tokens = |src: &str| {
+            syn::parse_file(src,)
+                .ok()
+                .and_then(|file| find_item(&file, name,),)
+                .map(|found| (found.to_token_stream().to_string(), found.span(),),)
+        };
+        let src = match fs::read_to_string(path,) {
+            Ok(src,) => src,
+            Err(_,) => return false,
+        };
+        let (existing, span,) = match tokens(&src,) {
+            Some(found,) => found,
+            None => return false,
+        };
+        let untouched = placeholders
+            .iter()
+            .filter_map(|placeholder| tokens(placeholder,),)
+            .any(|(placeholder, _,)| placeholder == existing,);
+        let replacement = match tokens(content,) {
+            Some((_, new_span,),) if untouched => {
+                let start = get_offset(content, new_span.start(),);
+                &content[start..get_offset(content, new_span.end(),)]
+            },
+            _ => return false,
+        };
+        let mut new_src = src.clone();
+        new_src.replace_range(
+            get_offset(&src, span.start(),)..get_offset(&src, span.end(),),
+            replacement,
+        );
+        match fs::write(path, new_src,) {
+            Ok(_,) => {
+                self.writer.log_ok(
+                    format!("replaced generated {} at {}", name, path.display()).as_str(),
+                );
+                true
+            },
+            Err(e,) => {
+                self.writer.log_error(
+                    format!("error {:?} when replacing {} at {}", e, name, path.display())
+                        .as_str(),
+                );
+                false
+            },
+        }
+    }
+
     /// Replace the existing imports.
     /// TODO : need to improve this dirty code.
     fn update_imports_to_write(
@@ -293,7 +391,7 @@ impl ContentManager {
                     self.writer.log_info(
                         format!(
                             "No need to update imports on {} for {}",
-                            parent_module.meta().filepath(),
+                            parent_module.meta().filepath().display(),
                             l
                         )
                         .as_str(),
@@ -309,20 +407,21 @@ impl ContentManager {
         let map = self.writer.content.modules().clone();
         let iter = map.iter();
         for (_, module,) in iter {
-            let path = module.meta().filepath().to_string();
-            self.writer.create_or_update_file(String::from(&path,),);
-            let (op, file,) = self.writer.files.get_mut(&path,).unwrap();
+            let path = module.meta().filepath().to_path_buf();
+            self.writer.create_or_update_file(&path,);
+            let (op, file,) = self.writer.get_file_mut(&path,).unwrap();
             match op {
                 FileOperation::Update => {
                     let mut src = String::new();
 
                     let read = file.read_to_string(&mut src,);
 
-                    if read.is_err() {
+                    if let Err(e,) = read {
+                        self.writer.log_error(
+                            format!("Should read file for  {}", path.display()).as_str(),
+                        );
                         self.writer
-                            .log_error(format!("Should read file for  {}", &path).as_str(),);
-                        self.writer
-                            .log_error(format!("{:?}", read.unwrap_err()).as_str(),);
+                            .log_error(format!("{:?}", e).as_str(),);
                     }
                     self.update_content_if_needed(&path, src.as_str(), module.clone(),);
                 },
@@ -337,32 +436,171 @@ impl ContentManager {
     /// For writing guard and local view on the target file.
     /// Could be extended for custom content maybe on any modules.
     pub fn add_or_update_local_content(&mut self,) -> &mut Self {
-        let path = self.writer.target_file_path.to_string();
+        let path = self.writer.target_file_path.clone();
         let views = self.writer.content.local_views().clone();
         let guards = self.writer.content.guards().clone();
         let view_updates = self.write_local_views(&path, &views,);
         let guard_updates = self.write_local_guards(&path, &guards,);
-        let updates: u32 = view_updates + guard_updates;
+        let state_updates = self.write_fetch_state(&path,);
+        let updates: u32 = view_updates + guard_updates + state_updates;
         if updates == 0 {
             self.file_ignored += 1;
         }
         self
     }
 
+    /// Add the missing arms dispatching the messages of the modules to the
+    /// `match msg` of the root `update`, the existing arms are left untouched.
+    pub fn add_or_update_dispatch_arms(&mut self,) -> &mut Self {
+        let path = self.writer.target_file_path.clone();
+        let src = match fs::read_to_string(&path,) {
+            Ok(src,) => src,
+            Err(e,) => {
+                self.writer
+                    .log_error(format!("Should read file for  {}", path.display()).as_str(),);
+                self.writer.log_error(format!("{:?}", e).as_str(),);
+                return self;
+            },
+        };
+        let update_match = match syn::parse_file(&src,).ok().as_ref().and_then(find_update_match,) {
+            Some(update_match,) => update_match,
+            None => {
+                self.writer
+                    .log_info("No match on msg in fn update [ => ] no dispatch arms added",);
+                return self;
+            },
+        };
+        let arms: Vec<String,> = self
+            .writer
+            .content
+            .dispatch_arms()
+            .iter()
+            .filter(|(variant, _,)| !update_match.variants.contains(variant,),)
+            .map(|(_, arm,)| arm.clone(),)
+            .collect();
+        if arms.is_empty() {
+            self.writer
+                .log_info("No need to add dispatch arms [ => ] as fn update has them all",);
+            return self;
+        }
+
+        let new_src = insert_before_brace(
+            &src,
+            update_match.end,
+            update_match.missing_comma,
+            &arms.concat(),
+        );
+        match fs::write(&path, new_src,) {
+            Ok(_,) => {
+                self.writer.log_ok(
+                    format!("added {} dispatch arms to fn update", arms.len()).as_str(),
+                );
+                self.file_updated += 1;
+            },
+            Err(e,) => self.writer.log_error(
+                format!("error {:?} when adding dispatch arms at {}", e, path.display())
+                    .as_str(),
+            ),
+        }
+        self
+    }
+
+    /// Write the `nav` view on the target file if asked, or replace it when
+    /// the routes changed since it was generated.
+    pub fn add_or_update_nav(&mut self,) -> &mut Self {
+        let nav = match self.writer.content.nav().clone() {
+            Some(nav,) => nav,
+            None => return self,
+        };
+        let path = self.writer.target_file_path.clone();
+        let src = match fs::read_to_string(&path,) {
+            Ok(src,) => src,
+            Err(e,) => {
+                self.writer
+                    .log_error(format!("Should read file for  {}", path.display()).as_str(),);
+                self.writer.log_error(format!("{:?}", e).as_str(),);
+                return self;
+            },
+        };
+        let existing = syn::parse_file(&src,)
+            .ok()
+            .and_then(|file| find_function(&file, "nav",),)
+            .map(|nav_fn| {
+                let span = nav_fn.span();
+                (get_offset(&src, span.start(),), get_offset(&src, span.end(),),)
+            },);
+        match existing {
+            None => {
+                self.writer.create_or_update_file(&path,);
+                self.write_on_file_with_custom_message(&path, &format!("\n{}", nav), "writing nav",);
+                self.file_updated += 1;
+            },
+            Some((start, end,),) if src[start..end].trim() == nav.trim() => {
+                self.writer
+                    .log_info("No need to update nav [ => ] as fn nav has every route",);
+                self.file_ignored += 1;
+            },
+            Some((start, end,),) => {
+                let mut new_src = src.clone();
+                new_src.replace_range(start..end, nav.trim(),);
+                match fs::write(&path, new_src,) {
+                    Ok(_,) => {
+                        self.writer.log_ok(
+                            format!("updated fn nav on {} with the routes", path.display())
+                                .as_str(),
+                        );
+                        self.file_updated += 1;
+                    },
+                    Err(e,) => self.writer.log_error(
+                        format!("error {:?} when updating nav at {}", e, path.display()).as_str(),
+                    ),
+                }
+            },
+        }
+        self
+    }
+
+    /// Write the routing tests on the target file if asked and not there yet.
+    pub fn add_or_update_routes_tests(&mut self,) -> &mut Self {
+        let tests = match self.writer.content.routes_tests().clone() {
+            Some(tests,) => tests,
+            None => return self,
+        };
+        let path = self.writer.target_file_path.clone();
+        let mut src = String::new();
+        self.writer.create_or_update_file(&path,);
+        let mut file = &self.writer.get_file_mut(&path,).unwrap().1;
+        if let Err(e,) = file.read_to_string(&mut src,) {
+            self.writer
+                .log_error(format!("Should read file for  {}", path.display()).as_str(),);
+            self.writer.log_error(format!("{:?}", e).as_str(),);
+        }
+
+        if Checker::check_local_mod_exist("routes_test", src.as_str(),) {
+            self.writer
+                .log_info("No need to create routes tests [ => ] as mod routes_test",);
+            self.file_ignored += 1;
+        } else {
+            self.write_on_file_with_custom_message(&path, tests.as_str(), "writing routes tests",);
+            self.file_updated += 1;
+        }
+        self
+    }
+
     /// Write local views on the targeted path.
-    fn write_local_views(&mut self, path: &str, views: &IndexMap<String, SeedView,>,) -> u32 {
+    fn write_local_views(&mut self, path: &Path, views: &IndexMap<String, SeedView,>,) -> u32 {
         let mut updates_number = 0;
         for (view_name, view,) in views {
             let mut src = String::new();
-            self.writer.create_or_update_file(path.to_string(),);
-            let mut file = &self.writer.files.get_mut(path,).unwrap().1;
+            self.writer.create_or_update_file(path,);
+            let mut file = &self.writer.get_file_mut(path,).unwrap().1;
             let read = file.read_to_string(&mut src,);
 
-            if read.is_err() {
+            if let Err(e,) = read {
                 self.writer
-                    .log_error(format!("Should read file for  {}", &path).as_str(),);
+                    .log_error(format!("Should read file for  {}", path.display()).as_str(),);
                 self.writer
-                    .log_error(format!("{:?}", read.unwrap_err()).as_str(),);
+                    .log_error(format!("{:?}", e).as_str(),);
             }
 
             let check = Checker::check_local_function_exist(view_name, src.as_str(),);
@@ -396,20 +634,37 @@ impl ContentManager {
         updates_number
     }
 
+    /// Write the `State` shared by the `#[fetch]` pages on the targeted path.
+    fn write_fetch_state(&mut self, path: &Path,) -> u32 {
+        let state = match self.writer.content.fetch_state().clone() {
+            Some(state,) => state,
+            None => return 0,
+        };
+        let src = fs::read_to_string(path,).unwrap_or_default();
+        if Checker::check_local_type_exist("State", &src,) {
+            self.writer
+                .log_info("No need to create State [ => ] as the target file has one",);
+            return 0;
+        }
+        self.write_on_file_with_custom_message(path, &state, "writing State of the fetch pages",);
+        self.file_updated += 1;
+        1
+    }
+
     /// Write local guard and redirect on the targeted path.
-    fn write_local_guards(&mut self, path: &str, guards: &IndexMap<String, SeedGuard,>,) -> u32 {
+    fn write_local_guards(&mut self, path: &Path, guards: &IndexMap<String, SeedGuard,>,) -> u32 {
         let mut updates_number = 0;
         for (guard_name, guard,) in guards {
             let mut src = String::new();
-            self.writer.create_or_update_file(path.to_string(),);
-            let mut file = &self.writer.files.get_mut(path,).unwrap().1;
+            self.writer.create_or_update_file(path,);
+            let mut file = &self.writer.get_file_mut(path,).unwrap().1;
             let read = file.read_to_string(&mut src,);
 
-            if read.is_err() {
+            if let Err(e,) = read {
                 self.writer
-                    .log_error(format!("Should read file for  {}", &path).as_str(),);
+                    .log_error(format!("Should read file for  {}", path.display()).as_str(),);
                 self.writer
-                    .log_error(format!("{:?}", read.unwrap_err()).as_str(),);
+                    .log_error(format!("{:?}", e).as_str(),);
             }
 
             let check = Checker::check_local_function_exist(guard_name, src.as_str(),);
@@ -438,7 +693,7 @@ impl ContentManager {
                 } else {
                     self.write_on_file_with_custom_message(
                         path,
-                        &guard.redirect.content.as_str(),
+                        guard.redirect.content.as_str(),
                         format!("writing redirect for guard as {}", &guard.redirect.name).as_str(),
                     );
                     self.write_on_file_with_custom_message(path, "\n", "Added indentation",);
@@ -453,3 +708,13 @@ impl ContentManager {
         updates_number
     }
 }
+
+/// The function, the struct or the module with this name.
+fn find_item(file: &syn::File, name: &str,) -> Option<Item,> {
+    file.items.iter().find_map(|item| match item {
+        Item::Fn(found,) if found.sig.ident == name => Some(item.clone(),),
+        Item::Struct(found,) if found.ident == name => Some(item.clone(),),
+        Item::Mod(found,) if found.ident == name => Some(item.clone(),),
+        _ => None,
+    },)
+}
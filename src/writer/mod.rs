@@ -1,14 +1,17 @@
 //! This module checks the existing files and write or update their content.
 
-use crate::content::SeedContent;
+use crate::{content::SeedContent, parser::update::get_offset, path::canonical_key};
 use indexmap::map::IndexMap;
 use indicatif::ProgressBar;
+use proc_macro2::LineColumn;
 use std::{
     fs,
     fs::{File, OpenOptions},
+    path::{Path, PathBuf},
 };
 
 pub mod checker;
+pub mod form;
 pub mod manager;
 
 #[derive(PartialEq, Clone)]
@@ -28,24 +31,26 @@ pub struct ModulesWriter {
     /// The path of the current target file
     /// `cargo run -- -g ./examples/backbone_app/src/lib.rs` ->
     /// `./examples/backbone_app/src/lib.rs`.
-    target_file_path: String,
+    target_file_path: PathBuf,
     /// The actual directory containing the target file from the CLI
     /// `cargo run -- -g ./examples/backbone_app/src/lib.rs` ->
     /// `./examples/backbone_app/src/`.
-    current_path: String,
+    #[allow(dead_code)]
+    current_path: PathBuf,
     /// List of folder created by the Writer
     /// For now it should be one if #[modules_path = "pages"].
-    folder_created: Vec<String,>,
-    /// List of files created or updated by their path.
-    files: IndexMap<String, (FileOperation, File,),>,
+    folder_created: Vec<PathBuf,>,
+    /// List of files created or updated by their canonical path, so
+    /// `./src/lib.rs` and `src//lib.rs` are the same file.
+    files: IndexMap<PathBuf, (FileOperation, File,),>,
 }
 
 impl ModulesWriter {
     pub fn new(
         content: SeedContent,
         pb: indicatif::ProgressBar,
-        current_path: String,
-        root_file_path: String,
+        current_path: PathBuf,
+        root_file_path: PathBuf,
     ) -> Self {
         ModulesWriter {
             content,
@@ -60,55 +65,61 @@ impl ModulesWriter {
 
     /// Create a folder and update the state of the Writer with it
     /// log or or info.
-    fn create_folder(&mut self, path: &str,) -> &mut Self {
+    fn create_folder(&mut self, path: &Path,) -> &mut Self {
         if fs::create_dir(path,).is_ok() {
-            self.log_ok(format!("created folder {}", path).as_str(),);
-            self.folder_created.push(path.to_string(),);
+            self.log_ok(format!("created folder {}", path.display()).as_str(),);
+            self.folder_created.push(path.to_path_buf(),);
         } else {
-            self.log_info(format!("will use folder {}", path).as_str(),);
+            self.log_info(format!("will use folder {}", path.display()).as_str(),);
         }
         self
     }
 
+    /// Get the file indexed for this path whatever the way it is written.
+    fn get_file_mut(&mut self, path: &Path,) -> Option<&mut (FileOperation, File,),> {
+        self.files.get_mut(&canonical_key(path,),)
+    }
+
     /// Open a filed to write and append code.
     /// If fail, then panic.
     /// If succeed then file is indexed so we can use it later with the path
     /// key.
-    fn open_file_with_panic(&mut self, path: &str,) -> &mut Self {
+    fn open_file_with_panic(&mut self, path: &Path,) -> &mut Self {
         let file = OpenOptions::new()
-            .write(true,)
+            
             .read(true,)
             .append(true,)
             .open(path,)
-            .unwrap_or_else(|_| panic!("Unable to update file , {}", path),);
+            .unwrap_or_else(|_| panic!("Unable to update file , {}", path.display()),);
         self.files
-            .insert(path.to_string(), (FileOperation::Update, file,),);
+            .insert(canonical_key(path,), (FileOperation::Update, file,),);
         self
     }
 
     /// Standard open file to write and happen code ot it.
-    fn open_file(path: &str,) -> std::io::Result<File,> {
+    fn open_file(path: &Path,) -> std::io::Result<File,> {
         OpenOptions::new()
             .read(true,)
-            .write(true,)
+            
             .append(true,)
             .open(path,)
     }
 
     /// Create a new file and index it in the state.
-    fn create_file(&mut self, path: String,) -> &mut Self {
-        match File::create(path.clone(),) {
+    fn create_file(&mut self, path: &Path,) -> &mut Self {
+        match File::create(path,) {
             Ok(file,) => {
                 self.files
-                    .insert(path.clone(), (FileOperation::Create, file,),);
-                self.log_ok(format!("created file at {} ", path).as_str(),);
+                    .insert(canonical_key(path,), (FileOperation::Create, file,),);
+                self.log_ok(format!("created file at {} ", path.display()).as_str(),);
             },
             Err(e,) => {
                 self.log_error(
                     format!(
                         "error {:?} when creating file at {}
 ",
-                        e, path
+                        e,
+                        path.display()
                     )
                     .as_str(),
                 );
@@ -118,11 +129,11 @@ impl ModulesWriter {
     }
 
     /// Create a new file and index it in the state or open a new file to.
-    fn create_or_update_file(&mut self, path: String,) -> &mut Self {
-        if let Ok(f,) = ModulesWriter::open_file(path.as_str(),) {
+    fn create_or_update_file(&mut self, path: &Path,) -> &mut Self {
+        if let Ok(f,) = ModulesWriter::open_file(path,) {
             self.files
-                .insert(path.clone(), (FileOperation::Update, f,),);
-            self.log_ok(format!("found file to update at {} ", path).as_str(),);
+                .insert(canonical_key(path,), (FileOperation::Update, f,),);
+            self.log_ok(format!("found file to update at {} ", path.display()).as_str(),);
         } else {
             self.create_file(path,);
         }
@@ -144,3 +155,26 @@ impl ModulesWriter {
         self.pb.println(format!("[!] {}", msg).as_str(),);
     }
 }
+
+/// Insert the content before the closing brace at `end`, keeping the
+/// indentation of the brace, with the comma the last element misses.
+pub fn insert_before_brace(
+    src: &str,
+    end: LineColumn,
+    missing_comma: Option<LineColumn,>,
+    content: &str,
+) -> String {
+    let end_offset = get_offset(src, end,);
+    let line_start = src[..end_offset].rfind('\n',).map(|i| i + 1,).unwrap_or(0,);
+    let (insert_at, prefix,) = if src[line_start..end_offset].trim().is_empty() {
+        (line_start, "",)
+    } else {
+        (end_offset, "\n",)
+    };
+    let mut new_src = src.to_string();
+    new_src.insert_str(insert_at, &format!("{}{}", prefix, content),);
+    if let Some(comma,) = missing_comma {
+        new_src.insert(get_offset(src, comma,), ',',);
+    }
+    new_src
+}